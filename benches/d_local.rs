@@ -11,6 +11,6 @@ use utc2k::Utc2k;
 
 
 benches!(
-	Bench::new("utc2k::Utc2k::now()").run(|| Utc2k::now()),
-	Bench::new("utc2k::Utc2k::now_local()").run(|| Utc2k::now_local()),
+	Bench::new("utc2k::Utc2k::now()").run(Utc2k::now),
+	Bench::new("utc2k::Utc2k::now_local()").run(Utc2k::now_local),
 );
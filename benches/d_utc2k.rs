@@ -7,12 +7,37 @@ use brunch::{
 	benches,
 };
 use utc2k::{
+	DateFormat,
 	FmtUtc2k,
 	Utc2k,
 };
 
 
 
+/// # Sample Timestamps.
+///
+/// One thousand ascending timestamps, spaced a little over seven minutes
+/// apart.
+const STAMPS: [u32; 1000] = {
+	let mut out = [0_u32; 1000];
+	let mut i = 0;
+	while i < 1000 {
+		out[i] = 1_624_593_661 + i as u32 * 433;
+		i += 1;
+	}
+	out
+};
+
+/// # Sample Date/Time Strings.
+///
+/// The same as [`STAMPS`], but formatted.
+fn rows() -> Vec<FmtUtc2k> { STAMPS.iter().copied().map(FmtUtc2k::from).collect() }
+
+/// # Custom Format Pattern.
+const PATTERN: &str = "[weekday], [month_name] [day@trim], [year] at [hour12@trim]:[minute] [ampm]";
+
+
+
 benches!(
 	Bench::new("utc2k::Utc2k::from(u32)")
 		.run(|| Utc2k::from(1_624_593_661_u32)),
@@ -25,6 +50,28 @@ benches!(
 
 	Bench::spacer(),
 
+	Bench::new("utc2k::Utc2k::from_unixtimes(&[u32; 1000])")
+		.run(|| Utc2k::from_unixtimes(&STAMPS)),
+
+	Bench::new("[u32; 1000].map(Utc2k::from)")
+		.run(|| STAMPS.iter().copied().map(Utc2k::from).collect::<Vec<_>>()),
+
+	Bench::spacer(),
+
+	Bench::new("utc2k::Utc2k::from_ascii_many(&[&[u8]; 1000])")
+		.run_seeded(rows(), |rows| {
+			let mut out = Vec::new();
+			Utc2k::from_ascii_many(rows.iter().map(FmtUtc2k::as_bytes), &mut out);
+			out
+		}),
+
+	Bench::new("[&[u8]; 1000].map(Utc2k::try_from)")
+		.run_seeded(rows(), |rows| rows.iter()
+			.map(|r| Utc2k::try_from(r.as_bytes()).ok())
+			.collect::<Vec<_>>()
+		),
+	Bench::spacer(),
+
 	Bench::new("utc2k::Utc2k::unixtime()")
 		.run_seeded(Utc2k::from(1_624_593_661_u32), Utc2k::unixtime),
 
@@ -38,4 +85,15 @@ benches!(
 
 	Bench::new("utc2k::Utc2k::from_rfc2822(Tue, 10 Jul 2003 10:52:37 +0000)")
 		.run(|| Utc2k::from_rfc2822("Tue, 10 Jul 2003 10:52:37 +0000")),
+
+	Bench::spacer(),
+
+	Bench::new("utc2k::Utc2k::formatted_custom()")
+		.run_seeded(Utc2k::from(Utc2k::MAX_UNIXTIME), |u| u.formatted_custom(PATTERN)),
+
+	Bench::new("utc2k::DateFormat::format()")
+		.run({
+			let fmt = DateFormat::compile(PATTERN).unwrap();
+			move || fmt.format(Utc2k::from(Utc2k::MAX_UNIXTIME))
+		}),
 );
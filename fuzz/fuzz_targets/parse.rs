@@ -0,0 +1,85 @@
+/*!
+# Fuzz: Parsing
+
+This throws arbitrary bytes at each of the public parsers to make sure none of
+them panic, and that anything successfully parsed survives a round trip.
+*/
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use utc2k::{
+	FmtUtc2k,
+	Month,
+	Utc2k,
+	Weekday,
+};
+
+
+
+fuzz_target!(|data: &[u8]| {
+	// Date/time (or date) slices.
+	if let Ok(date) = Utc2k::try_from(data) {
+		assert_eq!(Utc2k::try_from(date.formatted().as_bytes()), Ok(date));
+		assert_eq!(FmtUtc2k::try_from(data), Ok(date.formatted()));
+	}
+
+	// Explicit date/time formats.
+	if let Ok(date) = Utc2k::from_datetime_str(data) {
+		assert_eq!(Utc2k::from_datetime_str(date.formatted()), Ok(date));
+	}
+	if let Ok(date) = Utc2k::from_date_str(data) {
+		assert_eq!(Utc2k::from_datetime_str(date.formatted()), Ok(date));
+	}
+	if let Ok(date) = Utc2k::from_smooshed_datetime_str(data) {
+		assert_eq!(Utc2k::try_from(date.formatted().as_str()), Ok(date));
+	}
+	if let Ok(date) = Utc2k::from_smooshed_date_str(data) {
+		assert_eq!(Utc2k::try_from(date.formatted().as_str()), Ok(date));
+	}
+
+	// Times.
+	if let Ok((hh, mm, ss)) = Utc2k::parse_time_str(data) {
+		assert!(hh < 24 && mm < 60 && ss < 60);
+	}
+	if let Some(secs) = Utc2k::parse_hms(data) {
+		assert!(secs < utc2k::DAY_IN_SECONDS);
+		let date = Utc2k::MIN.with_time_from_ascii(data);
+		assert_eq!(date.map(Utc2k::seconds_from_midnight), Some(secs));
+	}
+	let _res = Utc2k::from_ascii_hms_units(data);
+
+	// Strict and basic formats.
+	if let Ok(date) = Utc2k::checked_from_ascii_strict(data) {
+		assert_eq!(Utc2k::checked_from_ascii_strict(date.formatted()), Ok(date));
+	}
+	if let Some(date) = Utc2k::from_iso8601_basic(data) {
+		assert_eq!(Utc2k::from_iso8601_basic(date.to_iso8601_basic()), Some(date));
+	}
+
+	// RFC3339 with offsets.
+	if let Some((date, offset)) = Utc2k::from_rfc3339_with_offset(data) {
+		assert_eq!(Utc2k::from_rfc3339(data), Some(date));
+		if let Ok(src) = date.to_rfc3339_with_offset(offset) {
+			assert_eq!(Utc2k::from_rfc3339_with_offset(src), Some((date, offset)));
+		}
+	}
+
+	// Packed.
+	if let Some(packed) = data.first_chunk::<5>() {
+		if let Some(date) = Utc2k::from_packed(*packed) {
+			assert_eq!(date.to_packed(), *packed);
+		}
+	}
+
+	// RFC2822, offset expressions, and the names.
+	if let Ok(src) = std::str::from_utf8(data) {
+		if let Some(date) = Utc2k::from_rfc2822(src) {
+			assert_eq!(Utc2k::from_rfc2822(date.to_rfc2822()), Some(date));
+		}
+
+		let _res = Utc2k::MIN.apply_offset_expr(src);
+		let _res = Month::try_from(src);
+		let _res = Weekday::try_from(src);
+	}
+});
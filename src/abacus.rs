@@ -3,6 +3,7 @@
 */
 
 use crate::{
+	DatePart,
 	DAY_IN_SECONDS,
 	HOUR_IN_SECONDS,
 	MINUTE_IN_SECONDS,
@@ -98,6 +99,40 @@ impl Abacus {
 		out
 	}
 
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	#[must_use]
+	/// # Parts (Unclamped).
+	///
+	/// Return the individual parts, nice and balanced, but _without_ clamping
+	/// the year to the century. (The year is returned in full.)
+	pub(super) const fn parts_unclamped(&self) -> (u32, u8, u8, u8, u8, u8) {
+		(
+			self.y,
+			self.m as u8,
+			self.d as u8,
+			self.hh as u8,
+			self.mm as u8,
+			self.ss as u8,
+		)
+	}
+
+	#[must_use]
+	/// # Unixtime (Unclamped).
+	///
+	/// Return the (balanced) date/time as a unix timestamp _without_ first
+	/// clamping it to the century, allowing offsets and the like to be
+	/// applied before any saturation occurs.
+	///
+	/// The day count uses Howard Hinnant's [`days_from_civil`](https://howardhinnant.github.io/date_algorithms.html#days_from_civil)
+	/// algorithm.
+	pub(super) const fn unixtime_unclamped(&self) -> i64 {
+		let days = days_from_civil(self.y as i64, self.m as i64, self.d as i64);
+		days * DAY_IN_SECONDS as i64 +
+		self.hh as i64 * HOUR_IN_SECONDS as i64 +
+		self.mm as i64 * MINUTE_IN_SECONDS as i64 +
+		self.ss as i64
+	}
+
 	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
 	#[must_use]
 	/// # Parts.
@@ -120,6 +155,39 @@ impl Abacus {
 	}
 }
 
+impl Abacus {
+	/// # Add To Part.
+	///
+	/// Add `n` to the specified part, then rebalance.
+	pub(super) fn add_part(&mut self, part: DatePart, n: u32) {
+		let v = self.part_mut(part);
+		*v = v.saturating_add(n);
+		self.rebalance();
+	}
+
+	/// # Set Part.
+	///
+	/// Replace the value of the specified part, then rebalance.
+	pub(super) fn set_part(&mut self, part: DatePart, value: u32) {
+		*self.part_mut(part) = value;
+		self.rebalance();
+	}
+
+	/// # Part (Mutable).
+	///
+	/// Return a mutable reference to the field corresponding to `part`.
+	const fn part_mut(&mut self, part: DatePart) -> &mut u32 {
+		match part {
+			DatePart::Year => &mut self.y,
+			DatePart::Month => &mut self.m,
+			DatePart::Day => &mut self.d,
+			DatePart::Hour => &mut self.hh,
+			DatePart::Minute => &mut self.mm,
+			DatePart::Second => &mut self.ss,
+		}
+	}
+}
+
 impl Abacus {
 	/// # Rebalance.
 	///
@@ -265,10 +333,92 @@ impl Abacus {
 
 
 
+/// # Days From Civil.
+///
+/// Return the number of days between the unix epoch and the given
+/// (balanced) year, month, and day, using Howard Hinnant's [`days_from_civil`](https://howardhinnant.github.io/date_algorithms.html#days_from_civil)
+/// algorithm.
+///
+/// Unlike everything else in this module, the year is not limited in any
+/// way, so this works for dates before `1970` (or `1500`) too.
+pub(super) const fn days_from_civil(y: i64, m: i64, d: i64) -> i64 {
+	// Years start in March for the purposes of this calculation.
+	let (y, m) =
+		if m <= 2 { (y - 1, m + 9) }
+		else { (y, m - 3) };
+
+	let era = y.div_euclid(400);
+	let yoe = y - era * 400;
+	let doy = (153 * m + 2) / 5 + d - 1;
+	let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+	era * 146_097 + doe - 719_468
+}
+
+#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+#[expect(clippy::cast_sign_loss, reason = "False positive.")]
+/// # Civil From Days.
+///
+/// The inverse of [`days_from_civil`]: return the year, month, and day
+/// falling the given number of days after (or before) the unix epoch, using
+/// Howard Hinnant's [`civil_from_days`](https://howardhinnant.github.io/date_algorithms.html#civil_from_days)
+/// algorithm.
+pub(super) const fn civil_from_days(days: i64) -> (i64, u8, u8) {
+	let days = days + 719_468;
+	let era = days.div_euclid(146_097);
+	let doe = days - era * 146_097;
+	let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+	let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+	let mp = (5 * doy + 2) / 153;
+	let d = (doy - (153 * mp + 2) / 5 + 1) as u8;
+	let m = (if mp < 10 { mp + 3 } else { mp - 9 }) as u8;
+	let y = yoe + era * 400;
+	(if m <= 2 { y + 1 } else { y }, m, d)
+}
+
+
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 
+	#[test]
+	/// # Unclamped Unixtime.
+	fn unixtime_unclamped() {
+		// In range, it should match Utc2k.
+		let mut rng = fastrand::Rng::new();
+		for i in std::iter::repeat_with(|| rng.u32(Utc2k::MIN_UNIXTIME..=Utc2k::MAX_UNIXTIME)).take(10_000) {
+			let tmp = Abacus::from(Utc2k::from(i));
+			assert_eq!(tmp.unixtime_unclamped(), i64::from(i));
+		}
+
+		// Out of range, it should keep going.
+		assert_eq!(Abacus::new(1970, 1, 1, 0, 0, 0).unixtime_unclamped(), 0);
+		assert_eq!(Abacus::new(1969, 12, 31, 23, 59, 59).unixtime_unclamped(), -1);
+		assert_eq!(
+			Abacus::new(1999, 12, 31, 20, 0, 0).unixtime_unclamped(),
+			i64::from(Utc2k::MIN_UNIXTIME) - 4 * 3600,
+		);
+		assert_eq!(
+			Abacus::new(2100, 1, 1, 0, 0, 0).unixtime_unclamped(),
+			i64::from(Utc2k::MAX_UNIXTIME) + 1,
+		);
+	}
+
+	#[test]
+	/// # Civil Days.
+	fn civil_days() {
+		// The two should round-trip over a wide range.
+		for days in -1_000_000..1_000_000 {
+			let (y, m, d) = civil_from_days(days);
+			assert_eq!(days_from_civil(y, m.into(), d.into()), days);
+		}
+
+		assert_eq!(civil_from_days(0), (1970, 1, 1));
+		assert_eq!(civil_from_days(-1), (1969, 12, 31));
+		assert_eq!(civil_from_days(10_957), (2000, 1, 1));
+		assert_eq!(days_from_civil(2100, 1, 1), 47_482);
+	}
+
 	#[test]
 	/// # Addition.
 	fn addition() {
@@ -4,12 +4,14 @@
 
 use crate::{
 	Abacus,
+	DAY_IN_SECONDS,
 	HOUR_IN_SECONDS,
 	JULIAN_EPOCH,
 	MINUTE_IN_SECONDS,
 	Month,
 	Utc2k,
 	Utc2kError,
+	Weekday,
 };
 
 
@@ -58,6 +60,113 @@ pub(super) const fn hms(src: &[u8]) -> Result<(u8, u8, u8), Utc2kError> {
 	Err(Utc2kError::Invalid)
 }
 
+/// # HMS (Flexible).
+///
+/// Parse out the hours, minutes, and seconds from a byte slice like
+/// `HH:MM:SS`, `HHMMSS`, or `HH:MM`, rejecting any values that are out of
+/// range. (Seconds default to zero when omitted.)
+pub(super) const fn hms_flexible(src: &[u8]) -> Option<(u8, u8, u8)> {
+	let (h1, h2, m1, m2, s1, s2) = match src.trim_ascii() {
+		[h1, h2, _, m1, m2, _, s1, s2] | [h1, h2, m1, m2, s1, s2] => (*h1, *h2, *m1, *m2, *s1, *s2),
+		[h1, h2, _, m1, m2] => (*h1, *h2, *m1, *m2, b'0', b'0'),
+		_ => return None,
+	};
+
+	if let Ok((hh, mm, ss)) = hms(&[h1, h2, b':', m1, m2, b':', s1, s2]) {
+		if hh < 24 && mm < 60 && ss < 60 { return Some((hh, mm, ss)); }
+	}
+
+	None
+}
+
+/// # HMS (Unit Separators).
+///
+/// This parses a `YYYY-MM-DD HHhMMm` or `YYYY-MM-DD HHhMMmSSs` datetime,
+/// where the time separators are the (lowercase) unit letters. The date/time
+/// separator must be a space or `T`; the date separators can be whatever.
+pub(super) fn hms_units(src: &[u8]) -> Option<Utc2k> {
+	let (date, rest) = src.split_first_chunk::<10>()?;
+	let (hh, mm, ss) = match rest {
+		[b' ' | b'T', h1, h2, b'h', m1, m2, b'm'] => (
+			parse2(*h1, *h2).ok()?,
+			parse2(*m1, *m2).ok()?,
+			0,
+		),
+		[b' ' | b'T', h1, h2, b'h', m1, m2, b'm', s1, s2, b's'] => leap_second(
+			parse2(*h1, *h2).ok()?,
+			parse2(*m1, *m2).ok()?,
+			parse2(*s1, *s2).ok()?,
+		),
+		_ => return None,
+	};
+
+	let tmp = Abacus::new(
+		parse4(date[0], date[1], date[2], date[3]).ok()?,
+		parse2(date[5], date[6]).ok()?,
+		parse2(date[8], date[9]).ok()?,
+		hh, mm, ss,
+	);
+
+	Some(Utc2k::from(tmp))
+}
+
+/// # Relative Offset Expression.
+///
+/// This parses one or more `Nd`/`Nh`/`Nm`/`Ns` tokens, each optionally
+/// preceded by a sign, returning the total number of seconds. Signs are
+/// sticky, applying to all subsequent unsigned tokens.
+pub(super) fn offset_expr(mut src: &[u8]) -> Option<i64> {
+	if src.is_empty() { return None; }
+
+	let mut total: i64 = 0;
+	let mut negative = false;
+	while ! src.is_empty() {
+		// Sign?
+		match src[0] {
+			b'+' => { negative = false; src = &src[1..]; },
+			b'-' => { negative = true; src = &src[1..]; },
+			_ => {},
+		}
+
+		// Digits.
+		let len = src.iter().take_while(|b| b.is_ascii_digit()).count();
+		if len == 0 { return None; }
+		let mut n: i64 = 0;
+		for b in &src[..len] {
+			n = n.checked_mul(10)?.checked_add(i64::from(b - b'0'))?;
+		}
+
+		// Unit.
+		let unit = match src.get(len)? {
+			b'd' => i64::from(DAY_IN_SECONDS),
+			b'h' => i64::from(HOUR_IN_SECONDS),
+			b'm' => i64::from(MINUTE_IN_SECONDS),
+			b's' => 1,
+			_ => return None,
+		};
+		src = &src[len + 1..];
+
+		let n = n.checked_mul(unit)?;
+		total =
+			if negative { total.checked_sub(n)? }
+			else { total.checked_add(n)? };
+	}
+
+	Some(total)
+}
+
+/// # Leap Second.
+///
+/// Clamp a `23:59:60` leap second to `23:59:59`, passing all other times
+/// through as-is.
+///
+/// This is only applied by the date/time parsers; time-only and strict
+/// parsing reject `:60` outright, and arithmetic carries as usual.
+pub(super) const fn leap_second(hh: u8, mm: u8, ss: u8) -> (u8, u8, u8) {
+	if hh == 23 && mm == 59 && ss == 60 { (23, 59, 59) }
+	else { (hh, mm, ss) }
+}
+
 /// # Parse 2 Digits.
 ///
 /// This combines two ASCII `u8` values into a single `u8` integer, or dies
@@ -91,14 +200,20 @@ pub(super) const fn parse4(a: u8, b: u8, c: u8, d: u8) -> Result<u16, Utc2kError
 /// This attempts to extract the year, month, and day from a `YYYY-MM-DD` byte
 /// slice. Only the numeric ranges are parsed — separators can be whatever.
 pub(super) fn parts_from_date(src: &[u8; 10]) -> Result<Utc2k, Utc2kError> {
-	let tmp = Abacus::new(
+	abacus_from_date(src).map(Utc2k::from)
+}
+
+/// # Parse Abacus From Date.
+///
+/// Same as [`parts_from_date`], but the result is left as a (balanced)
+/// [`Abacus`] so that it can be adjusted before being clamped to the century.
+pub(super) fn abacus_from_date(src: &[u8; 10]) -> Result<Abacus, Utc2kError> {
+	Ok(Abacus::new(
 		parse4(src[0], src[1], src[2], src[3])?,
 		parse2(src[5], src[6])?,
 		parse2(src[8], src[9])?,
 		0, 0, 0
-	);
-
-	Ok(Utc2k::from(tmp))
+	))
 }
 
 /// # Parse Parts From Date.
@@ -122,15 +237,23 @@ pub(super) fn parts_from_smooshed_date(src: [u8; 8]) -> Result<Utc2k, Utc2kError
 /// a `YYYY-MM-DD HH:MM:SS` byte slice. Only the numeric ranges are parsed —
 /// separators can be whatever.
 pub(super) fn parts_from_datetime(src: &[u8; 19]) -> Result<Utc2k, Utc2kError> {
+	abacus_from_datetime(src).map(Utc2k::from)
+}
+
+/// # Parse Abacus From Date/Time.
+///
+/// Same as [`parts_from_datetime`], but the result is left as a (balanced)
+/// [`Abacus`] so that it can be adjusted — i.e. for a UTC offset — before
+/// being clamped to the century.
+pub(super) fn abacus_from_datetime(src: &[u8; 19]) -> Result<Abacus, Utc2kError> {
 	let (hh, mm, ss) = hms(&src[11..])?;
-	let tmp = Abacus::new(
+	let (hh, mm, ss) = leap_second(hh, mm, ss);
+	Ok(Abacus::new(
 		parse4(src[0], src[1], src[2], src[3])?,
 		parse2(src[5], src[6])?,
 		parse2(src[8], src[9])?,
 		hh, mm, ss,
-	);
-
-	Ok(Utc2k::from(tmp))
+	))
 }
 
 /// # Parse Parts From Date/Time.
@@ -138,29 +261,129 @@ pub(super) fn parts_from_datetime(src: &[u8; 19]) -> Result<Utc2k, Utc2kError> {
 /// This attempts to extract the year, month, day, hour, minute and second from
 /// a `YYYYMMDDHHMMSS` byte slice.
 pub(super) fn parts_from_smooshed_datetime(src: &[u8; 14]) -> Result<Utc2k, Utc2kError> {
+	let (hh, mm, ss) = leap_second(
+		parse2(src[8], src[9])?,
+		parse2(src[10], src[11])?,
+		parse2(src[12], src[13])?,
+	);
 	let tmp = Abacus::new(
 		parse4(src[0], src[1], src[2], src[3])?,
 		parse2(src[4], src[5])?,
 		parse2(src[6], src[7])?,
-		parse2(src[8], src[9])?,
-		parse2(src[10], src[11])?,
-		parse2(src[12], src[13])?,
+		hh, mm, ss,
 	);
 
 	Ok(Utc2k::from(tmp))
 }
 
+/// # Parse Raw Parts.
+///
+/// This extracts the year, month, day, hour, minute, and second from a
+/// `YYYY-MM-DD HH:MM:SS` or `YYYY-MM-DD` byte slice _without_ rebalancing
+/// them. As with the other parsers, the length determines which format is
+/// used, and only the numeric ranges are parsed.
+pub(super) const fn raw_parts(src: &[u8]) -> Result<(u16, u8, u8, u8, u8, u8), Utc2kError> {
+	if src.len() < 10 { return Err(Utc2kError::TooShort); }
+
+	let Ok(y) = parse4(src[0], src[1], src[2], src[3]) else { return Err(Utc2kError::Invalid); };
+	let Ok(m) = parse2(src[5], src[6]) else { return Err(Utc2kError::Invalid); };
+	let Ok(d) = parse2(src[8], src[9]) else { return Err(Utc2kError::Invalid); };
+
+	if src.len() < 19 { Ok((y, m, d, 0, 0, 0)) }
+	else {
+		match hms(src.split_at(11).1) {
+			Ok((hh, mm, ss)) => Ok((y, m, d, hh, mm, ss)),
+			Err(e) => Err(e),
+		}
+	}
+}
+
+/// # Parse Prefix.
+///
+/// This parses a `YYYY-MM-DD[ HH:MM:SS[.fff]]` datetime (or date) anchored
+/// to the start of `src`, returning the value and the number of bytes
+/// consumed. Fractional seconds are consumed (but ignored); anything else
+/// is left for the caller.
+///
+/// Separators must be non-digits; the date/time separator must be a space
+/// or `T`. As with the other naive parsers, the numeric parts are
+/// rebalanced rather than rejected.
+pub(super) fn prefix(src: &[u8]) -> Option<(Utc2k, usize)> {
+	prefix_abacus(src).map(|(tmp, len)| (Utc2k::from(tmp), len))
+}
+
+/// # Parse Prefix (Abacus).
+///
+/// Same as [`prefix`], but the result is left as a (balanced) [`Abacus`] so
+/// that an offset can be applied before it is clamped to the century.
+pub(super) fn prefix_abacus(src: &[u8]) -> Option<(Abacus, usize)> {
+	/// # Not a Digit?
+	const fn sep(src: &[u8], idx: usize) -> bool {
+		idx < src.len() && ! src[idx].is_ascii_digit()
+	}
+
+	let date = src.first_chunk::<10>()?;
+	if ! sep(src, 4) || ! sep(src, 7) { return None; }
+
+	// Date and time.
+	if
+		matches!(src.get(10), Some(b' ' | b'T')) &&
+		src.get(13) == Some(&b':') &&
+		src.get(16) == Some(&b':')
+	{
+		if let Some(Ok(out)) = src.first_chunk::<19>().map(abacus_from_datetime) {
+			let mut len = 19;
+			if src.get(len) == Some(&b'.') {
+				let digits = src[len + 1..].iter().take_while(|b| b.is_ascii_digit()).count();
+				if digits != 0 { len += 1 + digits; }
+			}
+
+			if src.get(len).is_some_and(u8::is_ascii_digit) { return None; }
+			return Some((out, len));
+		}
+	}
+
+	// Just the date.
+	if src.get(10).is_some_and(u8::is_ascii_digit) { return None; }
+	abacus_from_date(date).ok().map(|d| (d, 10))
+}
+
+/// # Parse Offset Prefix.
+///
+/// This parses a `Z` or `±hh:mm`/`±hhmm` UTC offset anchored to the start of
+/// `src`, returning the offset in seconds and the number of bytes consumed.
+///
+/// `Ok(None)` is returned if there's no offset-like prefix to speak of, while
+/// an error is returned if there is one but its values are out of range.
+pub(super) fn offset_prefix(src: &[u8]) -> Result<Option<(i32, usize)>, Utc2kError> {
+	let (sign, h1, h2, m1, m2, len) = match src {
+		[b'Z' | b'z', ..] => return Ok(Some((0, 1))),
+		[sign @ (b'+' | b'-'), h1, h2, b':', m1, m2, ..] => (*sign, *h1, *h2, *m1, *m2, 6),
+		[sign @ (b'+' | b'-'), h1, h2, m1, m2, ..] => (*sign, *h1, *h2, *m1, *m2, 5),
+		_ => return Ok(None),
+	};
+
+	let (Ok(hh), Ok(mm)) = (parse2(h1, h2), parse2(m1, m2)) else { return Ok(None); };
+	if src.get(len).is_some_and(u8::is_ascii_digit) { return Ok(None); }
+	if 23 < hh || 59 < mm { return Err(Utc2kError::BadOffset); }
+
+	let offset = i32::from(hh) * 3600 + i32::from(mm) * 60;
+	Ok(Some((if sign == b'-' { -offset } else { offset }, len)))
+}
+
 /// # Parse RFC2822 Day.
 ///
 /// This method represents the second stage of [`Utc2k::from_rfc2822`]. It
 /// parses the month-day component from the string, moves the pointer, and
 /// passes it along to [`parse_rfc2822_datetime`] to finish it up.
-pub(super) fn rfc2822_day(src: &[u8]) -> Option<Utc2k> {
+///
+/// If a `weekday` is provided, the (pre-offset) date must fall on it.
+pub(super) fn rfc2822_day(src: &[u8], weekday: Option<Weekday>) -> Option<Utc2k> {
 	if 19 <= src.len() {
 		let a = src[0] ^ b'0';
 		if a < 10 {
 			if src[1] == b' ' {
-				return rfc2822_datetime(&src[2..], a);
+				return rfc2822_datetime(&src[2..], a, weekday);
 			}
 
 			let b = src[1] ^ b'0';
@@ -168,6 +391,7 @@ pub(super) fn rfc2822_day(src: &[u8]) -> Option<Utc2k> {
 				return rfc2822_datetime(
 					&src[3..],
 					a * 10 + b,
+					weekday,
 				);
 			}
 		}
@@ -176,6 +400,344 @@ pub(super) fn rfc2822_day(src: &[u8]) -> Option<Utc2k> {
 	None
 }
 
+/// # Find Embedded Datetime.
+///
+/// Scan `src` for the first embedded datetime, returning its byte span
+/// (start inclusive, end exclusive) and value.
+///
+/// Candidates must begin at a digit-run boundary, and take one of the
+/// following shapes:
+/// * `YYYY-MM-DD` with `-`, `/`, or `.` separators (used consistently);
+/// * The same followed by a space or `T` and `HH:MM:SS`, optional fractional
+///   seconds (ignored), and an optional `Z` or `±hh:mm`/`±hhmm` offset;
+/// * `YYYYMMDDHHMMSS` as a standalone 14-digit run.
+///
+/// Unlike the regular parsers, all parts must be strictly valid — nothing
+/// is rebalanced or saturated — to cut down on false positives. Candidates
+/// with malformed offsets, or that fall outside the century once the offset
+/// is applied, are skipped.
+pub(super) fn find(src: &[u8]) -> Option<(usize, usize, Utc2k)> {
+	let mut idx = 0;
+	while idx < src.len() {
+		if ! src[idx].is_ascii_digit() { idx += 1; continue; }
+
+		// Count the run.
+		let run = src[idx..].iter().take_while(|b| b.is_ascii_digit()).count();
+		let prev = if idx == 0 { None } else { Some(src[idx - 1]) };
+		let found = match run {
+			4 => find_separated(&src[idx..]),
+			14 if ! prev.is_some_and(|b| b.is_ascii_alphabetic()) => find_smooshed(&src[idx..]),
+			_ => None,
+		};
+		if let Some((len, date)) = found { return Some((idx, idx + len, date)); }
+
+		idx += run;
+	}
+
+	None
+}
+
+/// # Find Separated.
+///
+/// This handles the `YYYY-MM-DD[ HH:MM:SS[.fff][Z|±hh:mm|±hhmm]]` variants for
+/// [`find`], returning the length of the match and its value.
+fn find_separated(src: &[u8]) -> Option<(usize, Utc2k)> {
+	/// # Two Digits.
+	fn two(src: &[u8], idx: usize) -> Option<u8> {
+		parse2(*src.get(idx)?, *src.get(idx + 1)?).ok()
+	}
+
+	let y = parse4(src[0], src[1], src[2], src[3]).ok()?;
+	let sep = *src.get(4)?;
+	if ! matches!(sep, b'-' | b'/' | b'.') || src.get(7) != Some(&sep) { return None; }
+	let m = two(src, 5)?;
+	let d = two(src, 8)?;
+
+	// The time is optional.
+	let (mut len, hh, mm, ss) =
+		if
+			matches!(src.get(10), Some(b' ' | b'T')) &&
+			src.get(13) == Some(&b':') &&
+			src.get(16) == Some(&b':')
+		{
+			match (two(src, 11), two(src, 14), two(src, 17)) {
+				(Some(hh), Some(mm), Some(ss)) => {
+					let (hh, mm, ss) = leap_second(hh, mm, ss);
+					(19, hh, mm, ss)
+				},
+				_ => (10, 0, 0, 0),
+			}
+		}
+		else { (10, 0, 0, 0) };
+
+	let date = Utc2k::try_from((y, m, d, hh, mm, ss)).ok()?;
+	if len == 10 {
+		return
+			if src.get(10).is_some_and(u8::is_ascii_digit) { None }
+			else { Some((len, date)) };
+	}
+
+	// Skip fractional seconds.
+	if src.get(len) == Some(&b'.') {
+		let digits = src[len + 1..].iter().take_while(|b| b.is_ascii_digit()).count();
+		if digits != 0 { len += 1 + digits; }
+	}
+
+	// Apply an offset? Anything that looks like one but doesn't parse sinks
+	// the whole candidate.
+	let offset = match offset_prefix(&src[len..]) {
+		Ok(Some((offset, o_len))) => {
+			len += o_len;
+			offset
+		},
+		Ok(None) if matches!(src.get(len..len + 2), Some([b'+' | b'-', b'0'..=b'9'])) => return None,
+		Ok(None) => 0,
+		Err(_) => return None,
+	};
+
+	if src.get(len).is_some_and(u8::is_ascii_digit) { return None; }
+
+	// The result must still be in range.
+	let utc = i64::from(date.unixtime()) - i64::from(offset);
+	if (i64::from(Utc2k::MIN_UNIXTIME)..=i64::from(Utc2k::MAX_UNIXTIME)).contains(&utc) {
+		Some((len, Utc2k::from(u32::try_from(utc).ok()?)))
+	}
+	else { None }
+}
+
+/// # Find Smooshed.
+///
+/// This handles the `YYYYMMDDHHMMSS` variant for [`find`], returning the
+/// length of the match and its value.
+fn find_smooshed(src: &[u8]) -> Option<(usize, Utc2k)> {
+	if src.get(14).is_some_and(u8::is_ascii_alphabetic) { return None; }
+	let (hh, mm, ss) = leap_second(
+		parse2(src[8], src[9]).ok()?,
+		parse2(src[10], src[11]).ok()?,
+		parse2(src[12], src[13]).ok()?,
+	);
+	let date = Utc2k::try_from((
+		parse4(src[0], src[1], src[2], src[3]).ok()?,
+		parse2(src[4], src[5]).ok()?,
+		parse2(src[6], src[7]).ok()?,
+		hh, mm, ss,
+	)).ok()?;
+	Some((14, date))
+}
+
+/// # Parse asctime.
+///
+/// This parses the fixed-width `Www Mmm dd hh:mm:ss yyyy` layout produced by
+/// C's `asctime`/`ctime`, where single-digit days are space-padded.
+pub(super) fn asctime(src: &[u8]) -> Option<Utc2k> {
+	let [
+		w1, w2, w3, b' ',
+		m1, m2, m3, b' ',
+		d1, d2, b' ',
+		h1, h2, b':', i1, i2, b':', s1, s2, b' ',
+		y1, y2, y3, y4,
+	] = src else { return None; };
+
+	Weekday::from_abbreviation(&[*w1, *w2, *w3])?;
+	let (hh, mm, ss) = leap_second(
+		parse2(*h1, *h2).ok()?,
+		parse2(*i1, *i2).ok()?,
+		parse2(*s1, *s2).ok()?,
+	);
+	Some(Utc2k::from(Abacus::new(
+		parse4(*y1, *y2, *y3, *y4).ok()?,
+		Month::from_abbreviation(&[*m1, *m2, *m3])? as u8,
+		parse2(if *d1 == b' ' { b'0' } else { *d1 }, *d2).ok()?,
+		hh, mm, ss,
+	)))
+}
+
+/// # Parse HTTP Date.
+///
+/// This parses any of the three date formats HTTP/1.1 recipients are required
+/// to accept:
+/// * IMF-fixdate: `Sun, 06 Nov 1994 08:49:37 GMT`
+/// * RFC 850: `Sunday, 06-Nov-94 08:49:37 GMT`
+/// * asctime: `Sun Nov  6 08:49:37 1994`
+///
+/// Two-digit RFC 850 years more than fifty years in the future are moved back
+/// a century, per RFC 9110; dates before 2000 are then saturated to
+/// [`Utc2k::MIN`] as usual.
+pub(super) fn http_date(src: &[u8]) -> Option<Utc2k> {
+	let (y, m, d, time) = match src {
+		// IMF-fixdate.
+		[w1, w2, w3, b',', b' ', d1, d2, b' ', m1, m2, m3, b' ', y1, y2, y3, y4, b' ', time @ .., b' ', b'G', b'M', b'T']
+		if time.len() == 8 && Weekday::from_abbreviation(&[*w1, *w2, *w3]).is_some() => (
+			parse4(*y1, *y2, *y3, *y4).ok()?,
+			Month::from_abbreviation(&[*m1, *m2, *m3])?,
+			parse2(*d1, *d2).ok()?,
+			time,
+		),
+		// asctime.
+		[_, _, _, b' ', ..] => return asctime(src),
+		// RFC 850.
+		_ => {
+			let (name, rest) = src.split_last_chunk::<22>()?;
+			let name = name.strip_suffix(b", ")?;
+			if ! Weekday::from_abbreviation(name).is_some_and(|w| w.as_str().as_bytes().eq_ignore_ascii_case(name)) {
+				return None;
+			}
+
+			let [d1, d2, b'-', m1, m2, m3, b'-', y1, y2, b' ', time @ .., b' ', b'G', b'M', b'T'] = rest.as_slice()
+			else { return None; };
+
+			let mut y = 2000 + u16::from(parse2(*y1, *y2).ok()?);
+			if crate::year() + 50 < y { y -= 100; }
+
+			(
+				y,
+				Month::from_abbreviation(&[*m1, *m2, *m3])?,
+				parse2(*d1, *d2).ok()?,
+				time,
+			)
+		},
+	};
+
+	let (hh, mm, ss) = hms(time).ok()?;
+	let (hh, mm, ss) = leap_second(hh, mm, ss);
+	Some(Utc2k::from(Abacus::new(y, m as u8, d, hh, mm, ss)))
+}
+
+/// # Parse RFC3339.
+///
+/// This parses a `YYYY-MM-DDTHH:MM:SS` datetime followed by optional
+/// fractional seconds (which are ignored) and a mandatory `Z` or `±hh:mm`
+/// offset, returning the UTC equivalent along with the offset (in seconds).
+///
+/// Any trailing [RFC9557](https://datatracker.ietf.org/doc/html/rfc9557)
+/// `[...]` annotations are skipped over without interpretation.
+pub(super) fn rfc3339(src: &[u8]) -> Option<(Utc2k, i32)> {
+	let (date, mut rest) = strip_annotations(src)?.split_first_chunk::<19>()?;
+	if ! matches!(date[10], b'T' | b't' | b' ') { return None; }
+	let date = abacus_from_datetime(date).ok()?;
+
+	// Skip fractional seconds.
+	if let [b'.', r @ ..] = rest {
+		let digits = r.iter().take_while(|b| b.is_ascii_digit()).count();
+		if digits == 0 { return None; }
+		rest = &r[digits..];
+	}
+
+	let offset: i32 = match rest {
+		[b'Z' | b'z'] => 0,
+		[sign @ (b'+' | b'-'), h1, h2, b':', m1, m2] => {
+			let hh = parse2(*h1, *h2).ok()?;
+			let mm = parse2(*m1, *m2).ok()?;
+			if 23 < hh || 59 < mm { return None; }
+			let offset = i32::from(hh) * 3600 + i32::from(mm) * 60;
+			if *sign == b'-' { -offset } else { offset }
+		},
+		_ => return None,
+	};
+
+	Some((apply_offset(&date, offset), offset))
+}
+
+/// # Apply Offset.
+///
+/// Convert a wall-clock date/time with a known UTC offset (in seconds) to
+/// UTC. Saturation happens only _after_ the offset has been applied, so
+/// times just outside the century can still land within it.
+pub(super) fn apply_offset(date: &Abacus, offset: i32) -> Utc2k {
+	let utc = (date.unixtime_unclamped() - i64::from(offset))
+		.clamp(i64::from(Utc2k::MIN_UNIXTIME), i64::from(Utc2k::MAX_UNIXTIME));
+	u32::try_from(utc).map_or(Utc2k::MIN, Utc2k::from)
+}
+
+/// # Strip RFC9557 Annotations.
+///
+/// Remove any number of non-empty `[...]` groups from the end of the slice,
+/// returning what's left, or `None` if a group is malformed.
+fn strip_annotations(mut src: &[u8]) -> Option<&[u8]> {
+	while let [rest @ .., b']'] = src {
+		let idx = rest.iter().rposition(|&b| b == b'[')?;
+		let inner = &rest[idx + 1..];
+		if inner.is_empty() || inner.contains(&b']') { return None; }
+		src = &rest[..idx];
+	}
+	Some(src)
+}
+
+/// # Parse Date/Time With Named Zone.
+///
+/// This parses a `YYYY-MM-DD HH:MM:SS` datetime or `YYYY-MM-DD` date —
+/// nothing longer or shorter — followed by whitespace and a fixed-offset zone
+/// abbreviation from [`tz_offset`], returning the UTC equivalent.
+pub(super) fn named_tz(src: &[u8]) -> Result<Utc2k, Utc2kError> {
+	let idx = src.iter().rposition(u8::is_ascii_whitespace).ok_or(Utc2kError::BadOffset)?;
+	let offset = tz_offset(&src[idx + 1..]).ok_or(Utc2kError::BadOffset)?;
+
+	// The date/time should be followed only by the zone.
+	let date = src[..idx].trim_ascii_end();
+	let date =
+		if let Ok(date) = <&[u8; 19]>::try_from(date) { abacus_from_datetime(date)? }
+		else if let Ok(date) = <&[u8; 10]>::try_from(date) { abacus_from_date(date)? }
+		else { return Err(Utc2kError::TrailingData); };
+
+	Ok(apply_offset(&date, offset))
+}
+
+/// # Named Zone Offset.
+///
+/// Return the offset in seconds for a (case-insensitive) fixed-offset zone
+/// abbreviation. Abbreviations that mean different things in different
+/// regions are excluded, save for `CST`, which RFC2822 pins to North America.
+const fn tz_offset(src: &[u8]) -> Option<i32> {
+	/// # Helper: Hours (and Minutes) to Seconds.
+	const fn hm(hh: i32, mm: i32) -> i32 { hh * 3600 + mm * 60 }
+
+	let mut buf = [0_u8; 4];
+	if src.is_empty() || buf.len() < src.len() { return None; }
+	let mut i = 0;
+	while i < src.len() {
+		buf[i] = src[i].to_ascii_uppercase();
+		i += 1;
+	}
+
+	let offset = match buf.split_at(src.len()).0 {
+		// Universal.
+		b"Z" |
+		b"UT" |
+		b"UTC" |
+		b"GMT" |
+		b"WET" => 0,
+
+		// Europe.
+		b"WEST" |
+		b"CET" => hm(1, 0),
+		b"CEST" |
+		b"EET" => hm(2, 0),
+		b"EEST" |
+		b"MSK" => hm(3, 0),
+
+		// North America.
+		b"NDT" => hm(-2, -30),
+		b"NST" => hm(-3, -30),
+		b"ADT" => hm(-3, 0),
+		b"EDT" => hm(-4, 0),
+		b"EST" |
+		b"CDT" => hm(-5, 0),
+		b"CST" |
+		b"MDT" => hm(-6, 0),
+		b"MST" |
+		b"PDT" => hm(-7, 0),
+		b"PST" |
+		b"AKDT" => hm(-8, 0),
+		b"AKST" |
+		b"HDT" => hm(-9, 0),
+		b"HST" => hm(-10, 0),
+
+		_ => return None,
+	};
+
+	Some(offset)
+}
+
 #[expect(clippy::cast_possible_truncation, reason = "False positive.")]
 /// # Parse Time From Seconds.
 ///
@@ -211,10 +773,11 @@ pub(super) const fn time_seconds(mut src: u32) -> (u8, u8, u8) {
 /// This method represents the third stage of [`Utc2k::from_rfc2822`]. It
 /// parses the remaining date/time components from the string, applies the
 /// offset (if any), and returns the desired `Utc2k` object.
-fn rfc2822_datetime(src: &[u8], d: u8) -> Option<Utc2k> {
+fn rfc2822_datetime(src: &[u8], d: u8, weekday: Option<Weekday>) -> Option<Utc2k> {
 	// Grab the time bits.
 	let (src, time) = src.split_first_chunk::<9>()?;
 	let (hh, mm, ss) = hms(time).ok()?;
+	let (hh, mm, ss) = leap_second(hh, mm, ss);
 
 	// Parse out the rest!
 	let tmp = Abacus::new(
@@ -224,6 +787,12 @@ fn rfc2822_datetime(src: &[u8], d: u8) -> Option<Utc2k> {
 		hh, mm, ss,
 	);
 
+	// Verify the weekday? This has to happen before the offset is applied
+	// since the name refers to the stated date.
+	if let Some(weekday) = weekday {
+		if Utc2k::from(tmp).weekday() != weekday { return None; }
+	}
+
 	// Apply an offset?
 	if let Some((plus, offset_ss)) = rfc2822_offset(time) {
 		// The offset is beyond UTC; we need to subtract.
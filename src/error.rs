@@ -8,7 +8,12 @@ use std::error::Error;
 
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[non_exhaustive]
 /// # Errors.
+///
+/// This enum may grow new variants over time. To branch on the general
+/// _kind_ of error without exhaustively matching, use helpers like
+/// [`Utc2kError::is_out_of_range`] and [`Utc2kError::is_format`].
 pub enum Utc2kError {
 	/// # Invalid date/time format.
 	Invalid,
@@ -18,6 +23,23 @@ pub enum Utc2kError {
 
 	/// # Value is too small/early.
 	Underflow,
+
+	#[cfg(feature = "local")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "local")))]
+	/// # The local timezone could not be determined.
+	NoTimezone,
+
+	/// # A date/time component is out of range.
+	OutOfRange,
+
+	/// # The input is too short.
+	TooShort,
+
+	/// # Unexpected data follows the date/time.
+	TrailingData,
+
+	/// # Invalid UTC offset.
+	BadOffset,
 }
 
 impl Error for Utc2kError {}
@@ -32,9 +54,94 @@ impl Utc2kError {
 	/// Return the error as a string slice.
 	pub const fn as_str(self) -> &'static str {
 		match self {
-			Self::Invalid => "Invalid date/time format.",
-			Self::Overflow => "Date/time is post-2099.",
-			Self::Underflow => "Date/time is pre-2000.",
+			Self::Invalid => "Invalid date/time format; the digits or names could not be parsed.",
+			Self::Overflow => "Date/time is post-2099; the maximum is 2099-12-31 23:59:59.",
+			Self::Underflow => "Date/time is pre-2000; the minimum is 2000-01-01 00:00:00.",
+			#[cfg(feature = "local")]
+			Self::NoTimezone => "The local timezone could not be determined.",
+			Self::OutOfRange => "A date/time component is out of range (e.g. month 13 or hour 24).",
+			Self::TooShort => "Date/time is too short for the expected format.",
+			Self::TrailingData => "Unexpected data follows the date/time.",
+			Self::BadOffset => "Invalid UTC offset; expected something like +hh:mm.",
+		}
+	}
+
+	#[must_use]
+	/// # Is Format Error?
+	///
+	/// Returns `true` if the error stems from malformed input — bad digits,
+	/// a too-short or too-long string, an unparseable offset — rather than
+	/// well-formed values that happen to be out of range.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::{Utc2k, Utc2kError};
+	///
+	/// assert!(Utc2k::from_date_str("2024").unwrap_err().is_format());
+	/// assert!(Utc2k::from_date_str("Applebutter").unwrap_err().is_format());
+	/// assert!(! Utc2kError::Overflow.is_format());
+	/// ```
+	pub const fn is_format(self) -> bool {
+		matches!(self, Self::Invalid | Self::TooShort | Self::TrailingData | Self::BadOffset)
+	}
+
+	#[must_use]
+	/// # Is Out of Range?
+	///
+	/// Returns `true` if the error stems from a well-formed value that falls
+	/// outside the supported range, either because of the century limits or
+	/// because an individual component (like the month) is out of bounds.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::{Utc2k, Utc2kError};
+	///
+	/// assert!(Utc2k::checked_from_unixtime(0).unwrap_err().is_out_of_range());
+	/// assert!(Utc2k::checked_from_ascii_strict("2024-13-01").unwrap_err().is_out_of_range());
+	/// assert!(! Utc2kError::Invalid.is_out_of_range());
+	/// ```
+	pub const fn is_out_of_range(self) -> bool {
+		matches!(self, Self::Overflow | Self::Underflow | Self::OutOfRange)
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	/// # Error Kinds.
+	fn t_kinds() {
+		for e in [
+			Utc2kError::Invalid,
+			Utc2kError::Overflow,
+			Utc2kError::Underflow,
+			#[cfg(feature = "local")]
+			Utc2kError::NoTimezone,
+			Utc2kError::OutOfRange,
+			Utc2kError::TooShort,
+			Utc2kError::TrailingData,
+			Utc2kError::BadOffset,
+		] {
+			// No error is both, and only the timezone error is neither.
+			assert!(! (e.is_format() && e.is_out_of_range()), "{e:?} is both.");
+
+			#[cfg(feature = "local")]
+			let expected = e == Utc2kError::NoTimezone;
+			#[cfg(not(feature = "local"))]
+			let expected = false;
+
+			assert_eq!(
+				expected,
+				! (e.is_format() || e.is_out_of_range()),
+				"{e:?} is misclassified.",
+			);
+			assert!(e.source().is_none());
+			assert_eq!(e.to_string(), e.as_str());
 		}
 	}
 }
@@ -0,0 +1,680 @@
+/*!
+# UTC2K - Custom Formatting
+*/
+
+use crate::Utc2k;
+use std::{
+	error::Error,
+	fmt,
+};
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+/// # Padding.
+///
+/// This determines how numeric [`Component`]s are padded to their full
+/// width.
+///
+/// In format strings, padding is set by appending `@trim` or `@space` to the
+/// component name, e.g. `[day@trim]`.
+pub enum Padding {
+	#[default]
+	/// # Zeroes (e.g. `05`).
+	Zero,
+
+	/// # Spaces (e.g. ` 5`).
+	Space,
+
+	/// # None (e.g. `5`).
+	Trim,
+}
+
+
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+/// # Format Component.
+///
+/// This enum represents the individual pieces of a [`DateFormat`], i.e. the
+/// literal text and bracketed `[component]` placeholders.
+///
+/// | Placeholder | Component | Example |
+/// | ----------- | --------- | ------- |
+/// | `[year]` | [`Component::Year`] | `2025` |
+/// | `[year2]` | [`Component::Year2`] | `25` |
+/// | `[month]` | [`Component::Month`] | `06` |
+/// | `[month_name]` | [`Component::MonthName`] | `June` |
+/// | `[month_abbr]` | [`Component::MonthAbbr`] | `Jun` |
+/// | `[day]` | [`Component::Day`] | `05` |
+/// | `[weekday]` | [`Component::WeekdayName`] | `Thursday` |
+/// | `[weekday_abbr]` | [`Component::WeekdayAbbr`] | `Thu` |
+/// | `[ordinal]` | [`Component::Ordinal`] | `156` |
+/// | `[quarter]` | [`Component::Quarter`] | `2` |
+/// | `[week]` | [`Component::IsoWeek`] | `23` |
+/// | `[iso_year]` | [`Component::IsoYear`] | `2025` |
+/// | `[iso_weekday]` | [`Component::IsoWeekday`] | `4` |
+/// | `[hour]` | [`Component::Hour`] | `15` |
+/// | `[hour12]` | [`Component::Hour12`] | `03` |
+/// | `[ampm]` | [`Component::Period`] | `PM` |
+/// | `[minute]` | [`Component::Minute`] | `04` |
+/// | `[second]` | [`Component::Second`] | `09` |
+/// | `[unixtime]` | [`Component::Unixtime`] | `1749135849` |
+///
+/// Numeric components with a [`Padding`] can be suffixed with `@trim` or
+/// `@space` to change how they're padded. A literal `[` can be written as
+/// `[[`.
+///
+/// ## Examples
+///
+/// ```
+/// use utc2k::Utc2k;
+///
+/// let date = Utc2k::new(2025, 6, 15, 0, 0, 0);
+/// assert_eq!(
+///     date.formatted_custom("[iso_year]-W[week]-[iso_weekday]").unwrap(),
+///     "2025-W24-7",
+/// );
+/// assert_eq!(
+///     date.formatted_custom("Q[quarter] [year]").unwrap(),
+///     "Q2 2025",
+/// );
+/// ```
+pub enum Component {
+	/// # Literal Text.
+	Literal(String),
+
+	/// # Year (Four Digits).
+	Year,
+
+	/// # Year (Two Digits).
+	Year2(Padding),
+
+	/// # Month (Two Digits).
+	Month(Padding),
+
+	/// # Month Name.
+	MonthName,
+
+	/// # Month Abbreviation.
+	MonthAbbr,
+
+	/// # Day (Two Digits).
+	Day(Padding),
+
+	/// # Weekday Name.
+	WeekdayName,
+
+	/// # Weekday Abbreviation.
+	WeekdayAbbr,
+
+	/// # Day of Year (Three Digits).
+	Ordinal(Padding),
+
+	/// # Quarter (`1..=4`).
+	Quarter,
+
+	/// # ISO Week (Two Digits).
+	IsoWeek(Padding),
+
+	/// # ISO Year (Four Digits).
+	IsoYear,
+
+	/// # ISO Weekday (Monday = `1`, Sunday = `7`).
+	IsoWeekday,
+
+	/// # Hour (24-Hour, Two Digits).
+	Hour(Padding),
+
+	/// # Hour (12-Hour, Two Digits).
+	Hour12(Padding),
+
+	/// # AM/PM.
+	Period,
+
+	/// # Minute (Two Digits).
+	Minute(Padding),
+
+	/// # Second (Two Digits).
+	Second(Padding),
+
+	/// # Unix Timestamp.
+	Unixtime,
+}
+
+impl Component {
+	/// # Component Table.
+	///
+	/// The name, (default) component, and modifier support of each bracketed
+	/// placeholder. Parsing, compile-time validation, and error messages all
+	/// work from this list.
+	const TABLE: [(&'static str, Self, bool); 19] = [
+		("year", Self::Year, false),
+		("year2", Self::Year2(Padding::Zero), true),
+		("month", Self::Month(Padding::Zero), true),
+		("month_name", Self::MonthName, false),
+		("month_abbr", Self::MonthAbbr, false),
+		("day", Self::Day(Padding::Zero), true),
+		("weekday", Self::WeekdayName, false),
+		("weekday_abbr", Self::WeekdayAbbr, false),
+		("ordinal", Self::Ordinal(Padding::Zero), true),
+		("quarter", Self::Quarter, false),
+		("week", Self::IsoWeek(Padding::Zero), true),
+		("iso_year", Self::IsoYear, false),
+		("iso_weekday", Self::IsoWeekday, false),
+		("hour", Self::Hour(Padding::Zero), true),
+		("hour12", Self::Hour12(Padding::Zero), true),
+		("ampm", Self::Period, false),
+		("minute", Self::Minute(Padding::Zero), true),
+		("second", Self::Second(Padding::Zero), true),
+		("unixtime", Self::Unixtime, false),
+	];
+
+	/// # From Name.
+	///
+	/// Parse a bracketed component — sans brackets — like `day@trim`.
+	fn from_name(src: &str) -> Result<Self, Utc2kFormatError> {
+		let (name, pad) = match src.split_once('@') {
+			Some((name, "trim")) => (name, Some(Padding::Trim)),
+			Some((name, "space")) => (name, Some(Padding::Space)),
+			Some((_, modifier)) => return Err(Utc2kFormatError::UnknownModifier(modifier.to_owned())),
+			None => (src, None),
+		};
+
+		let (_, out, padded) = Self::TABLE.iter()
+			.find(|(n, _, _)| *n == name)
+			.ok_or_else(|| Utc2kFormatError::UnknownComponent(name.to_owned()))?;
+
+		match pad {
+			None => Ok(out.clone()),
+			Some(pad) if *padded => Ok(out.clone().with_padding(pad)),
+			// Modifiers only make sense for padded components.
+			Some(_) => Err(Utc2kFormatError::UnknownModifier(src.to_owned())),
+		}
+	}
+
+	/// # With Padding.
+	///
+	/// Swap out the padding of a padded component. Other components are
+	/// returned as-are.
+	fn with_padding(self, pad: Padding) -> Self {
+		match self {
+			Self::Year2(_) => Self::Year2(pad),
+			Self::Month(_) => Self::Month(pad),
+			Self::Day(_) => Self::Day(pad),
+			Self::Ordinal(_) => Self::Ordinal(pad),
+			Self::IsoWeek(_) => Self::IsoWeek(pad),
+			Self::Hour(_) => Self::Hour(pad),
+			Self::Hour12(_) => Self::Hour12(pad),
+			Self::Minute(_) => Self::Minute(pad),
+			Self::Second(_) => Self::Second(pad),
+			_ => self,
+		}
+	}
+
+	/// # Write Into.
+	fn write_into(&self, date: Utc2k, out: &mut String) {
+		match self {
+			Self::Literal(s) => out.push_str(s),
+			Self::Year => push_num(out, u32::from(date.year()), 4, Padding::Zero),
+			Self::Year2(pad) => push_num(out, u32::from(date.year() - 2000), 2, *pad),
+			Self::Month(pad) => push_num(out, u32::from(date.month()), 2, *pad),
+			Self::MonthName => out.push_str(date.month_enum().as_str()),
+			Self::MonthAbbr => out.push_str(date.month_enum().abbreviation()),
+			Self::Day(pad) => push_num(out, u32::from(date.day()), 2, *pad),
+			Self::WeekdayName => out.push_str(date.weekday().as_str()),
+			Self::WeekdayAbbr => out.push_str(date.weekday().abbreviation()),
+			Self::Ordinal(pad) => push_num(out, u32::from(date.ordinal()), 3, *pad),
+			Self::Quarter => push_num(out, u32::from(date.quarter()), 1, Padding::Trim),
+			Self::IsoWeek(pad) => push_num(out, u32::from(date.iso_week()), 2, *pad),
+			Self::IsoYear => push_num(out, u32::from(date.iso_year()), 4, Padding::Zero),
+			Self::IsoWeekday => push_num(out, (date.weekday() as u32 + 5) % 7 + 1, 1, Padding::Trim),
+			Self::Hour(pad) => push_num(out, u32::from(date.hour()), 2, *pad),
+			Self::Hour12(pad) => push_num(out, u32::from(date.hour12_parts().0), 2, *pad),
+			Self::Period => out.push_str(date.hour12_parts().1.as_str()),
+			Self::Minute(pad) => push_num(out, u32::from(date.minute()), 2, *pad),
+			Self::Second(pad) => push_num(out, u32::from(date.second()), 2, *pad),
+			Self::Unixtime => push_num(out, date.unixtime(), 1, Padding::Trim),
+		}
+	}
+}
+
+
+
+#[macro_export]
+/// # Compile-Time Checked [`DateFormat`].
+///
+/// This macro validates a literal format pattern at compile time — turning
+/// typos like `[monht]` into compile errors — and expands to the equivalent
+/// [`DateFormat`].
+///
+/// Refer to [`Component`] for the supported placeholders. For patterns that
+/// aren't known until runtime, use [`DateFormat::compile`] instead.
+///
+/// ## Examples
+///
+/// ```
+/// use utc2k::{datefmt, DateFormat, Utc2k};
+///
+/// let fmt: DateFormat = datefmt!("[year]-[month]-[day]");
+/// assert_eq!(fmt.format(Utc2k::new(2025, 6, 5, 0, 0, 0)), "2025-06-05");
+/// ```
+///
+/// Invalid patterns won't compile:
+///
+/// ```compile_fail
+/// let fmt = utc2k::datefmt!("[year]-[monht]-[day]");
+/// ```
+///
+/// ```compile_fail
+/// let fmt = utc2k::datefmt!("[year]-[month");
+/// ```
+///
+/// ```compile_fail
+/// let fmt = utc2k::datefmt!("[month_name@trim]");
+/// ```
+macro_rules! datefmt {
+	($fmt:literal $(,)?) => {{
+		const _: () = $crate::__private::validate_static($fmt);
+		$crate::DateFormat::compile($fmt).expect("validated at compile time")
+	}};
+}
+
+
+
+#[derive(Debug, Clone, Default, Eq, Hash, PartialEq)]
+/// # Compiled Date Format.
+///
+/// This holds a pre-parsed custom format pattern, allowing the same pattern
+/// to be applied to any number of [`Utc2k`] dates without re-parsing it each
+/// time.
+///
+/// Refer to [`Component`] for the supported placeholders.
+///
+/// ## Examples
+///
+/// ```
+/// use utc2k::{DateFormat, Utc2k};
+///
+/// let fmt = DateFormat::compile("[weekday], [month_name] [day@trim], [year]").unwrap();
+/// assert_eq!(
+///     fmt.format(Utc2k::new(2025, 6, 5, 15, 4, 9)),
+///     "Thursday, June 5, 2025",
+/// );
+/// assert_eq!(
+///     fmt.format(Utc2k::new(2025, 6, 6, 0, 0, 0)),
+///     "Friday, June 6, 2025",
+/// );
+/// ```
+pub struct DateFormat(Vec<Component>);
+
+impl TryFrom<&str> for DateFormat {
+	type Error = Utc2kFormatError;
+
+	#[inline]
+	fn try_from(src: &str) -> Result<Self, Self::Error> { Self::compile(src) }
+}
+
+impl DateFormat {
+	/// # Compile.
+	///
+	/// Parse a custom format pattern into its components.
+	///
+	/// ## Errors
+	///
+	/// An error is returned if the pattern contains an unknown component or
+	/// modifier, or an unclosed `[`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::DateFormat;
+	///
+	/// assert!(DateFormat::compile("[year]-[month]-[day]").is_ok());
+	/// assert!(DateFormat::compile("[year]-[monht]-[day]").is_err());
+	/// assert!(DateFormat::compile("[year]-[month").is_err());
+	/// ```
+	pub fn compile(fmt: &str) -> Result<Self, Utc2kFormatError> {
+		let mut out = Vec::new();
+		let mut literal = String::new();
+		let mut rest = fmt;
+
+		while let Some(idx) = rest.find('[') {
+			literal.push_str(&rest[..idx]);
+			rest = &rest[idx + 1..];
+
+			// An escaped bracket.
+			if let Some(next) = rest.strip_prefix('[') {
+				literal.push('[');
+				rest = next;
+				continue;
+			}
+
+			let (name, next) = rest.split_once(']').ok_or(Utc2kFormatError::Unclosed)?;
+			if ! literal.is_empty() {
+				out.push(Component::Literal(std::mem::take(&mut literal)));
+			}
+			out.push(Component::from_name(name)?);
+			rest = next;
+		}
+
+		literal.push_str(rest);
+		if ! literal.is_empty() { out.push(Component::Literal(literal)); }
+
+		Ok(Self(out))
+	}
+
+	#[inline]
+	#[must_use]
+	/// # Components.
+	///
+	/// Return the parsed components.
+	pub fn components(&self) -> &[Component] { &self.0 }
+
+	#[must_use]
+	/// # Format.
+	///
+	/// Format the date according to the pattern, returning a new `String`.
+	pub fn format(&self, date: Utc2k) -> String {
+		let mut out = String::new();
+		self.format_into(date, &mut out);
+		out
+	}
+
+	/// # Format Into.
+	///
+	/// Same as [`DateFormat::format`], but clears and writes to an existing
+	/// `String` buffer instead of allocating a new one.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::{DateFormat, Utc2k};
+	///
+	/// let fmt = DateFormat::compile("[month_abbr] [day@trim]").unwrap();
+	/// let mut buf = String::new();
+	///
+	/// fmt.format_into(Utc2k::new(2025, 6, 5, 0, 0, 0), &mut buf);
+	/// assert_eq!(buf, "Jun 5");
+	///
+	/// // The buffer is cleared first.
+	/// fmt.format_into(Utc2k::new(2025, 12, 25, 0, 0, 0), &mut buf);
+	/// assert_eq!(buf, "Dec 25");
+	/// ```
+	pub fn format_into(&self, date: Utc2k, out: &mut String) {
+		out.truncate(0);
+		for c in &self.0 { c.write_into(date, out); }
+	}
+}
+
+
+
+/// # Validate (Const).
+///
+/// This is a `const` version of the validation performed by
+/// [`DateFormat::compile`], used by the [`datefmt!`](crate::datefmt)
+/// macro. It panics if the pattern is invalid, which in a `const`
+/// context becomes a compile error.
+///
+/// This is not part of the public API; it is only reachable (by the macro)
+/// through the crate's private `__private` module.
+///
+/// ## Panics
+///
+/// Panics if the pattern is invalid.
+pub const fn validate_static(fmt: &str) {
+	/// # Byte Equality.
+	const fn eq(a: &[u8], b: &[u8]) -> bool {
+		if a.len() != b.len() { return false; }
+		let mut i = 0;
+		while i < a.len() {
+			if a[i] != b[i] { return false; }
+			i += 1;
+		}
+		true
+	}
+
+	/// # Lookup.
+	///
+	/// Return whether or not the named component supports modifiers, or
+	/// `None` if there is no such component.
+	const fn lookup(name: &[u8]) -> Option<bool> {
+		let mut i = 0;
+		while i < Component::TABLE.len() {
+			let (n, _, padded) = &Component::TABLE[i];
+			if eq(n.as_bytes(), name) { return Some(*padded); }
+			i += 1;
+		}
+		None
+	}
+
+	let bytes = fmt.as_bytes();
+	let mut i = 0;
+	while i < bytes.len() {
+		if bytes[i] != b'[' {
+			i += 1;
+			continue;
+		}
+
+		// An escaped bracket.
+		if i + 1 < bytes.len() && bytes[i + 1] == b'[' {
+			i += 2;
+			continue;
+		}
+
+		// Find the end.
+		let mut j = i + 1;
+		while j < bytes.len() && bytes[j] != b']' { j += 1; }
+		assert!(j < bytes.len(), "Unclosed format component.");
+
+		// Split off the modifier, if any.
+		let src = bytes.split_at(i + 1).1.split_at(j - i - 1).0;
+		let mut k = 0;
+		while k < src.len() && src[k] != b'@' { k += 1; }
+		let (name, modifier) = src.split_at(k);
+
+		let padded = lookup(name);
+		if modifier.is_empty() {
+			assert!(padded.is_some(), "Unknown format component.");
+		}
+		else {
+			assert!(
+				eq(modifier, b"@trim") || eq(modifier, b"@space"),
+				"Invalid format modifier; numeric components support @trim and @space.",
+			);
+			assert!(
+				matches!(padded, Some(true)),
+				"Unknown format component, or one that does not support modifiers.",
+			);
+		}
+
+		i = j + 1;
+	}
+}
+
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+/// # Format Error.
+///
+/// This is returned by [`DateFormat::compile`] when a pattern is invalid.
+pub enum Utc2kFormatError {
+	/// # Unknown Component.
+	UnknownComponent(String),
+
+	/// # Unknown/Unsupported Modifier.
+	UnknownModifier(String),
+
+	/// # Unclosed Bracket.
+	Unclosed,
+}
+
+impl Error for Utc2kFormatError {}
+
+impl fmt::Display for Utc2kFormatError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::UnknownComponent(s) => {
+				write!(f, "Unknown format component: [{s}]. Valid components are: ")?;
+				for (k, (name, _, _)) in Component::TABLE.iter().enumerate() {
+					if k != 0 { f.write_str(", ")?; }
+					f.write_str(name)?;
+				}
+				f.write_str(".")
+			},
+			Self::UnknownModifier(s) => write!(
+				f,
+				"Invalid format modifier: {s}. Numeric components support @trim and @space.",
+			),
+			Self::Unclosed => f.write_str("Unclosed format component."),
+		}
+	}
+}
+
+
+
+/// # Push Number.
+///
+/// Write a number to the buffer, padded to `width` as appropriate.
+fn push_num(out: &mut String, num: u32, width: usize, pad: Padding) {
+	let mut buf = [b'0'; 10];
+	let mut idx = buf.len();
+	let mut num = num;
+	loop {
+		idx -= 1;
+		buf[idx] += (num % 10) as u8;
+		num /= 10;
+		if num == 0 { break; }
+	}
+
+	let len = buf.len() - idx;
+	if len < width {
+		match pad {
+			Padding::Zero => for _ in len..width { out.push('0'); },
+			Padding::Space => for _ in len..width { out.push(' '); },
+			Padding::Trim => {},
+		}
+	}
+
+	for b in &buf[idx..] { out.push(char::from(*b)); }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[cfg(not(miri))]
+	const SAMPLE_SIZE: usize = 1_000_000;
+
+	#[cfg(miri)]
+	const SAMPLE_SIZE: usize = 1000; // Miri runs way too slow for a million tests.
+
+	#[test]
+	/// # Compilation.
+	fn t_compile() {
+		let fmt = DateFormat::compile("[[year] [year]!").unwrap();
+		assert_eq!(
+			fmt.components(),
+			&[
+				Component::Literal("[year] ".to_owned()),
+				Component::Year,
+				Component::Literal("!".to_owned()),
+			],
+		);
+
+		assert_eq!(
+			DateFormat::compile("[day@trim][hour@space]").unwrap().components(),
+			&[Component::Day(Padding::Trim), Component::Hour(Padding::Space)],
+		);
+
+		assert_eq!(DateFormat::compile(""), Ok(DateFormat::default()));
+		assert_eq!(
+			DateFormat::compile("[nope]"),
+			Err(Utc2kFormatError::UnknownComponent("nope".to_owned())),
+		);
+		assert!(matches!(
+			DateFormat::compile("[day@bold]"),
+			Err(Utc2kFormatError::UnknownModifier(_)),
+		));
+		assert!(matches!(
+			DateFormat::compile("[year@trim]"),
+			Err(Utc2kFormatError::UnknownModifier(_)),
+		));
+		assert_eq!(DateFormat::compile("[day"), Err(Utc2kFormatError::Unclosed));
+	}
+
+	#[test]
+	/// # Compile-Time Validation.
+	fn t_validate_static() {
+		for fmt in [
+			"", "plain text", "[[year]", "[year]-[month]-[day]", "[day@trim][hour@space]",
+			"[nope]", "[day@bold]", "[year@trim]", "[day", "[day@]", "[]", "[@trim]",
+			"[month_name] [weekday_abbr] [hour12@trim]:[minute] [ampm] [unixtime]",
+			"[iso_year]-W[week]-[iso_weekday] Q[quarter] [ordinal@space] [year2]",
+			"]", "[[", "[[[day]", "[a[b]",
+		] {
+			let valid = std::panic::catch_unwind(|| validate_static(fmt)).is_ok();
+			assert_eq!(valid, DateFormat::compile(fmt).is_ok(), "{fmt}");
+		}
+
+		// Every component, with and without modifiers.
+		for (name, component, padded) in Component::TABLE {
+			let fmt = format!("[{name}]");
+			validate_static(&fmt);
+			assert_eq!(DateFormat::compile(&fmt).unwrap().components(), &[component]);
+
+			for modifier in ["trim", "space"] {
+				let fmt = format!("[{name}@{modifier}]");
+				let valid = std::panic::catch_unwind(|| validate_static(&fmt)).is_ok();
+				assert_eq!(valid, padded, "{fmt}");
+				assert_eq!(DateFormat::compile(&fmt).is_ok(), padded, "{fmt}");
+			}
+		}
+
+		// And the macro.
+		assert_eq!(
+			crate::datefmt!("[year]-[month]-[day]"),
+			DateFormat::compile("[year]-[month]-[day]").unwrap(),
+		);
+	}
+
+	#[test]
+	/// # Formatting.
+	fn t_format() {
+		let full = DateFormat::compile("[year]-[month]-[day] [hour]:[minute]:[second]").unwrap();
+		let fancy = DateFormat::compile(
+			"[year2@trim]/[month@space]/[day@trim] [hour12]:[minute] [ampm] [weekday_abbr] [month_abbr] [ordinal] [unixtime] [iso_year]-W[week@trim]-[iso_weekday] Q[quarter]"
+		).unwrap();
+
+		let mut rng = fastrand::Rng::new();
+		let mut buf = String::new();
+		for i in std::iter::repeat_with(|| rng.u32(Utc2k::MIN_UNIXTIME..=Utc2k::MAX_UNIXTIME)).take(SAMPLE_SIZE) {
+			let date = Utc2k::from(i);
+			assert_eq!(full.format(date), date.formatted().as_str());
+			full.format_into(date, &mut buf);
+			assert_eq!(buf, date.formatted().as_str());
+
+			let hh = date.hour() % 12;
+			assert_eq!(
+				fancy.format(date),
+				format!(
+					"{}/{:>2}/{} {:02}:{:02} {} {} {} {:03} {} {}-W{}-{} Q{}",
+					date.year() - 2000,
+					date.month(),
+					date.day(),
+					if hh == 0 { 12 } else { hh },
+					date.minute(),
+					if date.hour() < 12 { "AM" } else { "PM" },
+					date.weekday().abbreviation(),
+					date.month_enum().abbreviation(),
+					date.ordinal(),
+					date.unixtime(),
+					date.iso_year(),
+					date.iso_week(),
+					(date.weekday() as u8 + 5) % 7 + 1,
+					(date.month() - 1) / 3 + 1,
+				),
+			);
+		}
+	}
+}
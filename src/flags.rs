@@ -0,0 +1,169 @@
+/*!
+# UTC2K - Parse Flags
+*/
+
+use std::{
+	fmt,
+	ops::{
+		BitAnd,
+		BitOr,
+		BitOrAssign,
+	},
+};
+
+
+
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+/// # Parse Flags.
+///
+/// A compact bitset describing how a date/time was parsed, as returned by
+/// [`Utc2k::from_ascii_annotated`](crate::Utc2k::from_ascii_annotated).
+///
+/// None of these conditions are errors — the regular parsers accept them
+/// all without comment — but they can be useful for flagging suspicious
+/// input.
+///
+/// Flags can be combined with `|` and tested with [`ParseFlags::contains`].
+///
+/// ## Examples
+///
+/// ```
+/// use utc2k::{ParseFlags, Utc2k};
+///
+/// let (date, flags) = Utc2k::from_ascii_annotated("2025-13-01").unwrap();
+/// assert_eq!(date, Utc2k::new(2026, 1, 1, 0, 0, 0));
+/// assert!(flags.contains(ParseFlags::REBALANCED | ParseFlags::DATE_ONLY));
+/// assert!(! flags.contains(ParseFlags::HAD_OFFSET));
+/// ```
+pub struct ParseFlags(u8);
+
+impl BitAnd for ParseFlags {
+	type Output = Self;
+
+	#[inline]
+	fn bitand(self, other: Self) -> Self { Self(self.0 & other.0) }
+}
+
+impl BitOr for ParseFlags {
+	type Output = Self;
+
+	#[inline]
+	fn bitor(self, other: Self) -> Self { Self(self.0 | other.0) }
+}
+
+impl BitOrAssign for ParseFlags {
+	#[inline]
+	fn bitor_assign(&mut self, other: Self) { self.0 |= other.0; }
+}
+
+impl fmt::Debug for ParseFlags {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_set()
+			.entries(
+				Self::NAMES.iter()
+					.filter_map(|(flag, name)| self.contains(*flag).then_some(name))
+			)
+			.finish()
+	}
+}
+
+impl From<ParseFlags> for u8 {
+	#[inline]
+	fn from(src: ParseFlags) -> Self { src.0 }
+}
+
+impl ParseFlags {
+	/// # Empty.
+	pub const EMPTY: Self = Self(0);
+
+	/// # Rebalanced.
+	///
+	/// One or more parts were out of range — month `13`, day `0`, etc. — and
+	/// had to be carried over into their neighbors.
+	pub const REBALANCED: Self = Self(0b0000_0001);
+
+	/// # Clamped (Low).
+	///
+	/// The value fell before `2000-01-01 00:00:00` and was saturated to
+	/// [`Utc2k::MIN`](crate::Utc2k::MIN).
+	pub const CLAMPED_LOW: Self = Self(0b0000_0010);
+
+	/// # Clamped (High).
+	///
+	/// The value fell after `2099-12-31 23:59:59` and was saturated to
+	/// [`Utc2k::MAX`](crate::Utc2k::MAX).
+	pub const CLAMPED_HIGH: Self = Self(0b0000_0100);
+
+	/// # Had Fraction.
+	///
+	/// The seconds were followed by a (discarded) fractional component.
+	pub const HAD_FRACTION: Self = Self(0b0000_1000);
+
+	/// # Had Offset.
+	///
+	/// The time was followed by a `Z` or `±hh:mm` UTC offset.
+	pub const HAD_OFFSET: Self = Self(0b0001_0000);
+
+	/// # Date Only.
+	///
+	/// There was no time component; midnight was assumed.
+	pub const DATE_ONLY: Self = Self(0b0010_0000);
+
+	/// # Names (For Debug).
+	const NAMES: [(Self, &'static str); 6] = [
+		(Self::REBALANCED, "REBALANCED"),
+		(Self::CLAMPED_LOW, "CLAMPED_LOW"),
+		(Self::CLAMPED_HIGH, "CLAMPED_HIGH"),
+		(Self::HAD_FRACTION, "HAD_FRACTION"),
+		(Self::HAD_OFFSET, "HAD_OFFSET"),
+		(Self::DATE_ONLY, "DATE_ONLY"),
+	];
+
+	#[must_use]
+	/// # Bits.
+	///
+	/// Return the raw bitmask.
+	pub const fn bits(self) -> u8 { self.0 }
+
+	#[must_use]
+	/// # Contains?
+	///
+	/// Returns `true` if _all_ of the flags in `other` are set.
+	pub const fn contains(self, other: Self) -> bool { self.0 & other.0 == other.0 }
+
+	#[must_use]
+	/// # Is Empty?
+	///
+	/// Returns `true` if no flags are set, i.e. the input was a plain,
+	/// in-range `YYYY-MM-DD HH:MM:SS`.
+	pub const fn is_empty(self) -> bool { self.0 == 0 }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	/// # Flag Basics.
+	fn t_flags() {
+		let mut flags = ParseFlags::EMPTY;
+		assert!(flags.is_empty());
+		assert_eq!(format!("{flags:?}"), "{}");
+
+		flags |= ParseFlags::REBALANCED;
+		flags |= ParseFlags::DATE_ONLY;
+		assert!(! flags.is_empty());
+		assert!(flags.contains(ParseFlags::REBALANCED));
+		assert!(flags.contains(ParseFlags::REBALANCED | ParseFlags::DATE_ONLY));
+		assert!(! flags.contains(ParseFlags::REBALANCED | ParseFlags::HAD_OFFSET));
+		assert_eq!(flags & ParseFlags::DATE_ONLY, ParseFlags::DATE_ONLY);
+		assert_eq!(u8::from(flags), 0b0010_0001);
+		assert_eq!(format!("{flags:?}"), r#"{"REBALANCED", "DATE_ONLY"}"#);
+
+		// The flags should all be distinct.
+		let all = ParseFlags::NAMES.iter().fold(ParseFlags::EMPTY, |acc, (f, _)| acc | *f);
+		assert_eq!(all.bits().count_ones(), 6);
+	}
+}
@@ -116,7 +116,6 @@ let s: &str = fmt.borrow();
 	clippy::rest_pat_in_fully_bound_structs,
 	clippy::semicolon_inside_block,
 	clippy::str_to_string,
-	clippy::string_to_string,
 	clippy::todo,
 	clippy::undocumented_unsafe_blocks,
 	clippy::unneeded_field_pattern,
@@ -134,9 +133,22 @@ let s: &str = fmt.borrow();
 	unused_import_braces,
 )]
 
-#![expect(clippy::module_name_repetitions, reason = "Repetition is preferred.")]
 #![expect(clippy::redundant_pub_crate, reason = "Unresolvable.")]
 
+#![cfg_attr(
+	test,
+	expect(
+		deprecated,
+		clippy::cast_lossless,
+		clippy::cast_possible_truncation,
+		clippy::cast_sign_loss,
+		clippy::doc_markdown,
+		clippy::semicolon_if_nothing_returned,
+		clippy::zero_prefixed_literal,
+		reason = "Legacy test code; newer toolchains are stricter.",
+	)
+)]
+
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 
@@ -144,16 +156,33 @@ let s: &str = fmt.borrow();
 mod abacus;
 mod date;
 mod error;
+mod fancy_fmt;
+mod flags;
 mod month;
+mod part;
+mod period;
+mod range;
+mod season;
+mod set;
 mod weekday;
 
 pub(crate) mod macros;
 
+#[doc(hidden)]
+/// # Macro Support.
+///
+/// Items the exported macros need to reach from downstream crates. This is
+/// not public API and may change at any time.
+pub mod __private {
+	pub use crate::fancy_fmt::validate_static;
+}
+
 #[cfg(feature = "local")]
 mod local;
 
 #[cfg(any(test, feature = "serde"))]
-mod serde;
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde;
 
 
 
@@ -163,12 +192,35 @@ pub use date::{
 	Utc2k,
 };
 pub use error::Utc2kError;
+pub use fancy_fmt::{
+	Component,
+	DateFormat,
+	Padding,
+	Utc2kFormatError,
+};
+pub use flags::ParseFlags;
 pub use month::Month;
+pub use part::DatePart;
+pub use period::Period;
+pub use range::{
+	Interval,
+	Utc2kRange,
+};
+pub use season::Season;
+pub use set::{
+	MonthSet,
+	MonthSetIter,
+	WeekdaySet,
+	WeekdaySetIter,
+};
 pub use weekday::Weekday;
 
 #[cfg(feature = "local")]
 #[cfg_attr(docsrs, doc(cfg(feature = "local")))]
-pub use local::LocalOffset;
+pub use local::{
+	LocalOffset,
+	OffsetSource,
+};
 
 
 
@@ -187,6 +239,12 @@ pub const WEEK_IN_SECONDS: u32 = 604_800;
 /// # Seconds per (Normal) Year.
 pub const YEAR_IN_SECONDS: u32 = 31_536_000;
 
+/// # NTP Epoch Offset.
+///
+/// The number of seconds between the NTP epoch (`1900-01-01 00:00:00`) and
+/// the Unix epoch (`1970-01-01 00:00:00`).
+pub const NTP_EPOCH_OFFSET: u32 = 2_208_988_800;
+
 /// # Julian Day Epoch.
 ///
 /// This is used internally when parsing date components from days.
@@ -217,6 +275,232 @@ pub fn unixtime() -> u32 {
 	)
 }
 
+#[must_use]
+/// # Is Leap Year?
+///
+/// This returns `true` if the given year is a leap year according to the
+/// standard Gregorian rules.
+///
+/// Unlike [`Utc2k::leap_year`], this works for any `u16` year, not just the
+/// ones a [`Utc2k`] can represent, making it useful for pre-validating
+/// arbitrary input.
+///
+/// ## Examples
+///
+/// ```
+/// assert!(utc2k::is_leap_year(2000));
+/// assert!(utc2k::is_leap_year(2024));
+/// assert!(! utc2k::is_leap_year(2100));
+/// assert!(! utc2k::is_leap_year(2025));
+/// ```
+pub const fn is_leap_year(y: u16) -> bool {
+	y.trailing_zeros() >= 2 && ((y % 100) != 0 || (y % 400) == 0)
+}
+
+#[must_use]
+/// # Year Length (Days).
+///
+/// Return the number of days in the given year — `365` or `366` — or `None`
+/// if the year falls outside `2000..=2099`.
+///
+/// ## Examples
+///
+/// ```
+/// assert_eq!(utc2k::year_length(2024), Some(366));
+/// assert_eq!(utc2k::year_length(2025), Some(365));
+/// assert_eq!(utc2k::year_length(2100), None);
+/// ```
+pub const fn year_length(y: u16) -> Option<u16> {
+	if 2000 <= y && y <= 2099 {
+		Some(if is_leap_year(y) { 366 } else { 365 })
+	}
+	else { None }
+}
+
+#[must_use]
+/// # Month Lengths.
+///
+/// Return the number of days in each month of the given year, January
+/// through December, accounting for leap Februaries.
+///
+/// Unlike most of this crate, this works for any year, not just those in
+/// `2000..=2099`.
+///
+/// ## Examples
+///
+/// ```
+/// assert_eq!(
+///     utc2k::month_lengths(2024),
+///     [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31],
+/// );
+/// assert_eq!(
+///     utc2k::month_lengths(2025),
+///     [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31],
+/// );
+/// ```
+pub const fn month_lengths(y: u16) -> [u8; 12] {
+	[31, if is_leap_year(y) { 29 } else { 28 }, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
+}
+
+#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+#[must_use]
+/// # Month Starts.
+///
+/// Return midnight on the first of each month of the given year, January
+/// through December, or `None` if the year falls outside `2000..=2099`.
+///
+/// The values are constructed directly, making this cheaper than calling
+/// [`Utc2k::new`] twelve times. Pair it with [`month_lengths`] to get the
+/// full shape of a year.
+///
+/// ## Examples
+///
+/// ```
+/// use utc2k::Utc2k;
+///
+/// let starts = utc2k::month_starts(2024).unwrap();
+/// assert_eq!(starts[0], Utc2k::new(2024, 1, 1, 0, 0, 0));
+/// assert_eq!(starts[11], Utc2k::new(2024, 12, 1, 0, 0, 0));
+///
+/// assert!(utc2k::month_starts(2100).is_none());
+/// ```
+pub const fn month_starts(y: u16) -> Option<[Utc2k; 12]> {
+	if 2000 <= y && y <= 2099 { Some(Utc2k::month_starts((y - 2000) as u8)) }
+	else { None }
+}
+
+#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+#[must_use]
+/// # Year Starts On.
+///
+/// Return the [`Weekday`] of January 1st for the given year, or `None` if the
+/// year falls outside `2000..=2099`.
+///
+/// ## Examples
+///
+/// ```
+/// use utc2k::Weekday;
+///
+/// assert_eq!(utc2k::year_starts_on(2000), Some(Weekday::Saturday));
+/// assert_eq!(utc2k::year_starts_on(2024), Some(Weekday::Monday));
+/// assert_eq!(utc2k::year_starts_on(1999), None);
+/// ```
+pub const fn year_starts_on(y: u16) -> Option<Weekday> {
+	if 2000 <= y && y <= 2099 { Some(Weekday::year_begins_on((y - 2000) as u8)) }
+	else { None }
+}
+
+#[must_use]
+/// # Rebalance Parts.
+///
+/// Rebalance a set of `(year, month, day, hour, minute, second)` parts —
+/// carrying over any overflows, e.g. 13 months becomes +1 year and 1 month —
+/// and clamp the result to the century, exactly as [`Utc2k::new`] would,
+/// returning the corrected parts as plain integers.
+///
+/// This is handy for previewing the effects of user input without having to
+/// round-trip through a [`Utc2k`].
+///
+/// ## Examples
+///
+/// ```
+/// assert_eq!(
+///     utc2k::rebalance_parts(2020, 13, 32, 24, 60, 60),
+///     (2021, 2, 2, 1, 1, 0),
+/// );
+///
+/// // Clamped to the century.
+/// assert_eq!(
+///     utc2k::rebalance_parts(1999, 1, 1, 0, 0, 0),
+///     (2000, 1, 1, 0, 0, 0),
+/// );
+/// ```
+pub fn rebalance_parts(y: u16, m: u8, d: u8, hh: u8, mm: u8, ss: u8)
+-> (u16, u8, u8, u8, u8, u8) {
+	let (y, m, d, hh, mm, ss) = Abacus::new(y, m, d, hh, mm, ss).parts();
+	(u16::from(y) + 2000, m, d, hh, mm, ss)
+}
+
+#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+#[must_use]
+/// # Normalize Parts.
+///
+/// Rebalance a set of `(year, month, day, hour, minute, second)` parts using
+/// the same rules as [`Utc2k::new`], but _without_ clamping the result to the
+/// century, so the true normalized year — `1999`, `2101`, etc. — can be seen
+/// and dealt with however the caller sees fit.
+///
+/// The rules are:
+/// * Excess seconds, minutes, and hours carry over to the next unit, e.g. `60` seconds become `+1` minute and `0` seconds;
+/// * Excess days carry over to the following month(s), e.g. `02-30` becomes `03-01` or `03-02` depending on the year;
+/// * Excess months carry over to the following year(s), e.g. `13` becomes `+1` year and January;
+/// * A zero month rewinds to December of the previous year;
+/// * A zero day rewinds to the last day of the previous month;
+///
+/// Unlike [`rebalance_parts`], the non-year inputs are `u16`s, allowing for
+/// larger starting values, and the year is returned as an `i32` so that
+/// no result, however early or late, ever needs to be clamped.
+///
+/// ## Examples
+///
+/// ```
+/// // Carries.
+/// assert_eq!(
+///     utc2k::normalize_parts(2099, 12, 31, 23, 59, 60),
+///     (2100, 1, 1, 0, 0, 0),
+/// );
+/// assert_eq!(
+///     utc2k::normalize_parts(2020, 1, 0, 0, 0, 0),
+///     (2019, 12, 31, 0, 0, 0),
+/// );
+/// assert_eq!(
+///     utc2k::normalize_parts(2024, 2, 30, 0, 0, 0),
+///     (2024, 3, 1, 0, 0, 0),
+/// );
+/// assert_eq!(
+///     utc2k::normalize_parts(2000, 0, 1, 0, 0, 0),
+///     (1999, 12, 1, 0, 0, 0),
+/// );
+/// assert_eq!(
+///     utc2k::normalize_parts(2000, 1, 1, 0, 0, 3600),
+///     (2000, 1, 1, 1, 0, 0),
+/// );
+///
+/// // Versus rebalance_parts, which clamps.
+/// assert_eq!(
+///     utc2k::rebalance_parts(2000, 0, 1, 0, 0, 0),
+///     (2000, 1, 1, 0, 0, 0),
+/// );
+/// ```
+pub const fn normalize_parts(y: u16, m: u16, d: u16, hh: u16, mm: u16, ss: u16)
+-> (i32, u8, u8, u8, u8, u8) {
+	// Rewind or carry the month first.
+	let (y, m) =
+		if m == 0 { (y as i64 - 1, 12) }
+		else { (y as i64 + ((m - 1) / 12) as i64, ((m - 1) % 12 + 1) as i64) };
+
+	// The time can be flattened into seconds, then split into whole days and
+	// the remainder.
+	let secs = hh as u32 * HOUR_IN_SECONDS + mm as u32 * MINUTE_IN_SECONDS + ss as u32;
+
+	// Days are relative to the first of the (balanced) month, so a zero day
+	// naturally rewinds to the end of the previous one.
+	let days = abacus::days_from_civil(y, m, 1) +
+		d as i64 - 1 +
+		(secs / DAY_IN_SECONDS) as i64;
+	let (y, m, d) = abacus::civil_from_days(days);
+
+	let secs = secs % DAY_IN_SECONDS;
+	(
+		y as i32,
+		m,
+		d,
+		(secs / HOUR_IN_SECONDS) as u8,
+		(secs % HOUR_IN_SECONDS / MINUTE_IN_SECONDS) as u8,
+		(secs % MINUTE_IN_SECONDS) as u8,
+	)
+}
+
 #[must_use]
 /// # Now (Current Year).
 ///
@@ -241,6 +525,199 @@ mod test {
 	use super::*;
 	use std::time::SystemTime;
 
+	#[test]
+	fn t_is_leap_year() {
+		for y in 0..=u16::MAX {
+			assert_eq!(
+				is_leap_year(y),
+				time::util::is_leap_year(i32::from(y)),
+				"Leap mismatch: {y}",
+			);
+		}
+	}
+
+	#[test]
+	fn t_year_facts() {
+		for y in 1990..=2110_u16 {
+			if (2000..=2099).contains(&y) {
+				let first = Utc2k::new(y, 1, 1, 0, 0, 0);
+				assert_eq!(year_starts_on(y), Some(first.weekday()));
+				assert_eq!(year_length(y), Some(if first.leap_year() { 366 } else { 365 }));
+			}
+			else {
+				assert!(year_starts_on(y).is_none());
+				assert!(year_length(y).is_none());
+			}
+		}
+	}
+
+	#[test]
+	fn t_month_lengths() {
+		for y in 2000..=2099 {
+			let lengths = month_lengths(y);
+			for (m, len) in (1..=12).zip(lengths) {
+				assert_eq!(Utc2k::new(y, m, 1, 0, 0, 0).month_size(), len, "{y}-{m}");
+			}
+			assert_eq!(lengths.iter().map(|&n| u16::from(n)).sum::<u16>(), year_length(y).unwrap());
+		}
+	}
+
+	#[test]
+	fn t_month_starts() {
+		for y in 2000..=2099 {
+			let starts = month_starts(y).expect("Missing month starts.");
+			let lengths = month_lengths(y);
+			for (m, start) in (1..=12).zip(starts) {
+				assert_eq!(start, Utc2k::new(y, m, 1, 0, 0, 0), "{y}-{m}");
+			}
+
+			// The gaps between them should match the month lengths.
+			let mut next = starts[0];
+			for (start, len) in starts.into_iter().zip(lengths) {
+				assert_eq!(start, next);
+				next = start.add_days(u32::from(len));
+			}
+			assert_eq!(next.ymd(), if y == 2099 { (2099, 12, 31) } else { (y + 1, 1, 1) });
+		}
+
+		assert!(month_starts(1999).is_none());
+		assert!(month_starts(2100).is_none());
+	}
+
+	#[test]
+	fn t_rebalance_parts() {
+		let mut rng = fastrand::Rng::new();
+		for _ in 0..1000 {
+			let parts = (rng.u16(1990..2110), rng.u8(..), rng.u8(..), rng.u8(..), rng.u8(..), rng.u8(..));
+			let (y, m, d, hh, mm, ss) = parts;
+			assert_eq!(
+				rebalance_parts(y, m, d, hh, mm, ss),
+				Utc2k::new(y, m, d, hh, mm, ss).parts(),
+				"{parts:?}",
+			);
+		}
+	}
+
+	#[test]
+	fn t_normalize_parts() {
+		/// # Naive Normalization.
+		///
+		/// Walk the calendar one day at a time.
+		fn naive_normalize(y: u16, m: u16, d: u16, hh: u16, mm: u16, ss: u16)
+		-> (i32, u8, u8, u8, u8, u8) {
+			fn month_len(y: i32, m: u8) -> u8 {
+				match m {
+					1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+					4 | 6 | 9 | 11 => 30,
+					_ if y.rem_euclid(4) == 0 && (y.rem_euclid(100) != 0 || y.rem_euclid(400) == 0) => 29,
+					_ => 28,
+				}
+			}
+
+			let mut y = i32::from(y);
+			let mut m = m;
+			if m == 0 {
+				y -= 1;
+				m = 12;
+			}
+			while 12 < m {
+				y += 1;
+				m -= 12;
+			}
+			let mut m = u8::try_from(m).unwrap();
+
+			let secs = u32::from(hh) * 3600 + u32::from(mm) * 60 + u32::from(ss);
+			let mut dd = 1;
+			let forward = |y: &mut i32, m: &mut u8, dd: &mut u8| {
+				*dd += 1;
+				if month_len(*y, *m) < *dd {
+					*dd = 1;
+					*m += 1;
+					if *m == 13 {
+						*m = 1;
+						*y += 1;
+					}
+				}
+			};
+
+			if d == 0 {
+				m -= 1;
+				if m == 0 {
+					m = 12;
+					y -= 1;
+				}
+				dd = month_len(y, m);
+			}
+			else {
+				for _ in 1..d { forward(&mut y, &mut m, &mut dd); }
+			}
+			for _ in 0..secs / 86_400 { forward(&mut y, &mut m, &mut dd); }
+
+			let secs = secs % 86_400;
+			(
+				y,
+				m,
+				dd,
+				u8::try_from(secs / 3600).unwrap(),
+				u8::try_from(secs % 3600 / 60).unwrap(),
+				u8::try_from(secs % 60).unwrap(),
+			)
+		}
+
+		// In-range results should match the clamping version.
+		let mut rng = fastrand::Rng::new();
+		for _ in 0..1000 {
+			let parts = (rng.u16(2001..2098), rng.u8(1..=12), rng.u8(1..=28), rng.u8(..), rng.u8(..), rng.u8(..));
+			let (y, m, d, hh, mm, ss) = parts;
+			let (y2, m2, d2, hh2, mm2, ss2) = rebalance_parts(y, m, d, hh, mm, ss);
+			assert_eq!(
+				normalize_parts(y, m.into(), d.into(), hh.into(), mm.into(), ss.into()),
+				(i32::from(y2), m2, d2, hh2, mm2, ss2),
+				"{parts:?}",
+			);
+		}
+
+		// Rewinds.
+		assert_eq!(normalize_parts(2000, 0, 0, 0, 0, 0), (1999, 11, 30, 0, 0, 0));
+		assert_eq!(normalize_parts(2000, 1, 0, 0, 0, 0), (1999, 12, 31, 0, 0, 0));
+		assert_eq!(normalize_parts(2000, 3, 0, 0, 0, 0), (2000, 2, 29, 0, 0, 0));
+		assert_eq!(normalize_parts(2100, 3, 0, 0, 0, 0), (2100, 2, 28, 0, 0, 0));
+
+		// Carries.
+		assert_eq!(normalize_parts(2099, 13, 1, 0, 0, 0), (2100, 1, 1, 0, 0, 0));
+		assert_eq!(normalize_parts(2099, 12, 32, 0, 0, 0), (2100, 1, 1, 0, 0, 0));
+		assert_eq!(normalize_parts(2099, 12, 31, 24, 0, 0), (2100, 1, 1, 0, 0, 0));
+		assert_eq!(normalize_parts(2099, 12, 31, 23, 60, 0), (2100, 1, 1, 0, 0, 0));
+		assert_eq!(normalize_parts(2000, 25, 1, 0, 0, 0), (2002, 1, 1, 0, 0, 0));
+		assert_eq!(normalize_parts(2000, 1, 366, 0, 0, 0), (2000, 12, 31, 0, 0, 0));
+		assert_eq!(normalize_parts(2000, 1, 1, 0, 0, u16::MAX), (2000, 1, 1, 18, 12, 15));
+		assert_eq!(normalize_parts(2000, 1, 1, 0, 1440, 0), (2000, 1, 2, 0, 0, 0));
+
+		// Zero days rewind unless time carries into them.
+		assert_eq!(normalize_parts(2000, 3, 0, 24, 0, 0), (2000, 3, 1, 0, 0, 0));
+		assert_eq!(normalize_parts(2000, 0, 0, 23, 59, 59), (1999, 11, 30, 23, 59, 59));
+
+		// Extremes aren't clamped.
+		assert_eq!(normalize_parts(0, 0, 0, 0, 0, 0), (-1, 11, 30, 0, 0, 0));
+		assert_eq!(normalize_parts(1200, 2, 29, 0, 0, 0), (1200, 2, 29, 0, 0, 0));
+		assert_eq!(normalize_parts(u16::MAX, 13, 1, 0, 0, 0), (65_536, 1, 1, 0, 0, 0));
+		assert_eq!(
+			normalize_parts(u16::MAX, u16::MAX, u16::MAX, u16::MAX, u16::MAX, u16::MAX),
+			(71_183, 3, 12, 21, 27, 15),
+		);
+
+		// Compare against a naive day-by-day walk for a spread of inputs.
+		for _ in 0..1000 {
+			let (y, m, d) = (rng.u16(1400..2200), rng.u16(0..40), rng.u16(0..400));
+			let (hh, mm, ss) = (rng.u16(..), rng.u16(..), rng.u16(..));
+			assert_eq!(
+				normalize_parts(y, m, d, hh, mm, ss),
+				naive_normalize(y, m, d, hh, mm, ss),
+				"{:?}", (y, m, d, hh, mm, ss),
+			);
+		}
+	}
+
 	#[test]
 	fn t_unixtime() {
 		// Our method.
@@ -5,11 +5,19 @@
 use crate::{
 	FmtUtc2k,
 	Utc2k,
+	Utc2kError,
 };
 use std::{
+	cmp::Ordering,
+	hash::{
+		Hash,
+		Hasher,
+	},
 	ops::Neg,
-	sync::OnceLock,
 };
+#[cfg(not(windows))]
+use std::sync::OnceLock;
+#[cfg(not(windows))]
 use tz::timezone::{
 	LocalTimeType,
 	TimeZone,
@@ -18,11 +26,16 @@ use tz::timezone::{
 
 
 #[cfg_attr(docsrs, doc(cfg(feature = "local")))]
-#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+#[derive(Debug, Clone, Copy, Default)]
 /// # Local Offset.
 ///
 /// This struct attempts to determine the appropriate UTC offset for the local
-/// timezone in a thread-safe manner, but **only for unix systems**.
+/// timezone in a thread-safe manner, but **only for unix and Windows
+/// systems**.
+///
+/// On unix, the `TZ` environmental variable and `/etc/localtime` are
+/// consulted; on Windows, the system's timezone rules are queried via
+/// `GetTimeZoneInformationForYear`.
 ///
 /// Instantiation will never fail, though.
 ///
@@ -84,19 +97,55 @@ use tz::timezone::{
 /// let offset = LocalOffset::from(946_684_800_u32);
 /// let utc = Utc2k::from(-offset);
 /// ```
+///
+/// Equality and hashing are based solely on the underlying UTC timestamp —
+/// [`LocalOffset::unixtime`] — so two `LocalOffset`s representing the same
+/// moment are equal regardless of their offsets or [`OffsetSource`]s. This
+/// keeps them consistent with the cross-type comparisons against [`Utc2k`]
+/// and [`FmtUtc2k`], which work the same way.
+///
+/// ```
+/// use utc2k::LocalOffset;
+///
+/// let a = LocalOffset::from(946_684_800_u32);
+/// let b = a.with_offset(3600);
+/// assert_eq!(a, b);
+/// ```
+///
+/// Because failed lookups are treated as UTC, you may want to check
+/// [`LocalOffset::source`] — or use [`LocalOffset::checked_from_utc2k`] —
+/// to make sure the offset actually came from the system.
 pub struct LocalOffset {
 	/// # Unixtime (Seconds).
 	unixtime: u32,
 
 	/// # Offset (Seconds).
 	offset: i32,
+
+	/// # Offset Source.
+	source: OffsetSource,
+}
+
+impl Eq for LocalOffset {}
+
+impl Hash for LocalOffset {
+	#[inline]
+	fn hash<H: Hasher>(&self, state: &mut H) { self.unixtime.hash(state); }
+}
+
+impl PartialEq for LocalOffset {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool { self.unixtime == other.unixtime }
 }
 
 impl From<u32> for LocalOffset {
 	#[inline]
 	fn from(unixtime: u32) -> Self {
-		let offset = offset(unixtime);
-		Self { unixtime, offset }
+		let (offset, source) = offset(unixtime).map_or(
+			(0, OffsetSource::None),
+			|offset| (offset, OffsetSource::System),
+		);
+		Self { unixtime, offset, source }
 	}
 }
 
@@ -118,12 +167,14 @@ impl Neg for LocalOffset {
 			Self {
 				unixtime: self.unixtime,
 				offset: i32::MAX,
+				source: self.source,
 			}
 		}
 		else {
 			Self {
 				unixtime: self.unixtime,
 				offset: self.offset.wrapping_neg(),
+				source: self.source,
 			}
 		}
 	}
@@ -144,6 +195,193 @@ impl LocalOffset {
 	/// ```
 	pub fn now() -> Self { Self::from(crate::unixtime()) }
 
+	#[inline]
+	#[must_use]
+	/// # Today.
+	///
+	/// Return the offset for the start of the current day in the local
+	/// timezone, i.e. local midnight. The [`LocalOffset::unixtime`] is the
+	/// corresponding UTC instant, while [`LocalOffset::localtime`] lands on
+	/// `00:00:00`.
+	///
+	/// Daylight saving transitions are accounted for. In the rare timezones
+	/// where the clocks spring forward _at_ midnight, the day begins at the
+	/// first valid local time instead (e.g. `01:00:00`).
+	///
+	/// If no offset can be determined, this is equivalent to
+	/// [`Utc2k::today`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::{LocalOffset, Utc2k};
+	///
+	/// let today = LocalOffset::today();
+	/// let local = Utc2k::from(today);
+	/// assert_eq!(local.to_midnight(), Utc2k::now_local().to_midnight());
+	/// ```
+	pub fn today() -> Self {
+		Self::from(local_midnight(crate::unixtime(), offset))
+	}
+
+	#[inline]
+	#[must_use]
+	/// # Today (UTC).
+	///
+	/// Return the UTC datetime corresponding to the start of the current day
+	/// in the local timezone.
+	///
+	/// See [`LocalOffset::today`] for more details.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::LocalOffset;
+	///
+	/// assert_eq!(
+	///     LocalOffset::today_utc().unixtime(),
+	///     LocalOffset::today().unixtime(),
+	/// );
+	/// ```
+	pub fn today_utc() -> Utc2k { Utc2k::from(Self::today().unixtime) }
+
+	/// # Checked From `Utc2k`.
+	///
+	/// Same as the `From<Utc2k>` implementation, except an error is returned
+	/// instead of silently falling back to UTC or saturating the local time.
+	///
+	/// As with `From<Utc2k>`, this should only be used for `Utc2k` instances
+	/// holding honest UTC datetimes.
+	///
+	/// ## Errors
+	///
+	/// If the local timezone cannot be determined, [`Utc2kError::NoTimezone`]
+	/// is returned.
+	///
+	/// If the offset would push the local time outside the 2000..=2099 range
+	/// supported by [`Utc2k`], [`Utc2kError::Underflow`] or
+	/// [`Utc2kError::Overflow`] is returned instead.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::{LocalOffset, Utc2k, Utc2kError};
+	///
+	/// match LocalOffset::checked_from_utc2k(Utc2k::now()) {
+	///     Ok(offset) => { /* The offset is trustworthy. */ },
+	///     Err(Utc2kError::NoTimezone) => { /* Not so much. */ },
+	///     Err(_) => { /* The local time doesn't fit. */ },
+	/// }
+	/// ```
+	pub fn checked_from_utc2k(src: Utc2k) -> Result<Self, Utc2kError> {
+		let out = Self::from(src);
+		if matches!(out.source, OffsetSource::None) {
+			return Err(Utc2kError::NoTimezone);
+		}
+
+		let local = i64::from(out.unixtime) + i64::from(out.offset);
+		if local < i64::from(Utc2k::MIN_UNIXTIME) { Err(Utc2kError::Underflow) }
+		else if i64::from(Utc2k::MAX_UNIXTIME) < local { Err(Utc2kError::Overflow) }
+		else { Ok(out) }
+	}
+
+	#[must_use]
+	/// # With Time.
+	///
+	/// Return a new instance for the same _local_ date at a different _local_
+	/// time, re-resolving the offset for the result rather than reusing the
+	/// current one, since the two might fall on either side of a daylight
+	/// saving transition.
+	///
+	/// As with [`Utc2k::with_time`], out-of-range values are carried over.
+	///
+	/// Local times that don't exist — skipped by the clocks springing
+	/// forward — are pushed ahead by the length of the gap (e.g. `02:30`
+	/// becomes `03:30`), while those that occur twice keep the current
+	/// offset, if possible.
+	///
+	/// Instances created with [`LocalOffset::with_offset`] keep their fixed
+	/// offset instead.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::{LocalOffset, Utc2k};
+	///
+	/// // Nine o'clock this morning.
+	/// let morning = LocalOffset::now().with_time(9, 0, 0);
+	/// assert_eq!(
+	///     Utc2k::from(morning).to_midnight(),
+	///     Utc2k::now_local().to_midnight(),
+	/// );
+	/// ```
+	pub fn with_time(self, hh: u8, mm: u8, ss: u8) -> Self {
+		self.relocalize(Utc2k::from(self).with_time(hh, mm, ss))
+	}
+
+	#[must_use]
+	/// # Add Days.
+	///
+	/// Return a new instance `n` _local_ calendar days later, at the same
+	/// _local_ time, re-resolving the offset for the result rather than
+	/// reusing the current one. (Across a daylight saving transition, this
+	/// may be more or less than `n * 86_400` seconds later.)
+	///
+	/// See [`LocalOffset::with_time`] for details about nonexistent and
+	/// ambiguous local times, and fixed offsets.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::{LocalOffset, Utc2k};
+	///
+	/// // Tomorrow at nine.
+	/// let then = LocalOffset::now().add_days(1).with_time(9, 0, 0);
+	/// assert_eq!(
+	///     Utc2k::from(then).to_midnight(),
+	///     Utc2k::now_local().to_midnight().add_days(1),
+	/// );
+	/// ```
+	pub fn add_days(self, n: u32) -> Self {
+		self.relocalize(Utc2k::from(self).add_days(n))
+	}
+
+	#[must_use]
+	/// # With Offset.
+	///
+	/// Return a new instance for the same instant — [`LocalOffset::unixtime`]
+	/// is unchanged — but expressed in a different, fixed offset (in
+	/// seconds), bypassing the system timezone lookup entirely.
+	///
+	/// The [`LocalOffset::source`] of the result will be
+	/// [`OffsetSource::Fixed`].
+	///
+	/// As with any other `LocalOffset`, wall times pushed outside the century
+	/// by the offset will be saturated when converted to [`Utc2k`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::{LocalOffset, OffsetSource, Utc2k};
+	///
+	/// let utc = Utc2k::new(2025, 6, 15, 12, 30, 0);
+	/// let pst = LocalOffset::from(utc).with_offset(-8 * 3600);
+	/// assert_eq!(Utc2k::from(pst), Utc2k::new(2025, 6, 15, 4, 30, 0));
+	///
+	/// // Show a colleague in Paris.
+	/// let cet = pst.with_offset(3600);
+	/// assert_eq!(Utc2k::from(cet), Utc2k::new(2025, 6, 15, 13, 30, 0));
+	/// assert_eq!(cet.unixtime(), utc.unixtime());
+	/// assert_eq!(cet.source(), OffsetSource::Fixed);
+	/// ```
+	pub const fn with_offset(self, offset: i32) -> Self {
+		Self {
+			unixtime: self.unixtime,
+			offset,
+			source: OffsetSource::Fixed,
+		}
+	}
+
 	#[must_use]
 	/// # Local Timestamp.
 	///
@@ -165,6 +403,26 @@ impl LocalOffset {
 	/// offset, or no offset could be determined.
 	pub const fn offset(self) -> i32 { self.offset }
 
+	#[inline]
+	#[must_use]
+	/// # Offset Source.
+	///
+	/// Return where the offset came from, i.e. whether it was determined by
+	/// the system, set explicitly, or simply assumed to be zero because no
+	/// timezone details could be found.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::{LocalOffset, OffsetSource};
+	///
+	/// let offset = LocalOffset::now();
+	/// if offset.source() == OffsetSource::None {
+	///     assert_eq!(offset.offset(), 0);
+	/// }
+	/// ```
+	pub const fn source(self) -> OffsetSource { self.source }
+
 	#[inline]
 	#[must_use]
 	/// # Unixtime.
@@ -183,32 +441,419 @@ impl LocalOffset {
 	pub const fn unixtime(self) -> u32 { self.unixtime }
 }
 
+impl LocalOffset {
+	/// # Re-Localize.
+	///
+	/// Return a new instance for the given _local_ wall time, resolving its
+	/// offset the same way ours was: fixed offsets are reused as-is, while
+	/// everything else is looked up afresh.
+	fn relocalize(self, local: Utc2k) -> Self {
+		let local = i64::from(local.unixtime());
+		if matches!(self.source, OffsetSource::Fixed) {
+			Self {
+				unixtime: local_to_utc(local, self.unixtime, |_| Some(self.offset)),
+				..self
+			}
+		}
+		else { Self::from(local_to_utc(local, self.unixtime, offset)) }
+	}
+}
+
 impl From<LocalOffset> for i32 {
 	fn from(src: LocalOffset) -> Self { src.offset }
 }
 
 impl From<LocalOffset> for FmtUtc2k {
+	/// # From `LocalOffset`.
+	///
+	/// As with the [`Utc2k`] equivalent, this returns the _local_ wall time,
+	/// which will not compare equal to the source for non-zero offsets.
 	fn from(src: LocalOffset) -> Self { Self::from(Utc2k::from(src)) }
 }
 
 impl From<LocalOffset> for Utc2k {
 	#[inline]
+	/// # From `LocalOffset`.
+	///
+	/// This returns the _local_ wall time — the "trick" described in the
+	/// [`LocalOffset`] docs — rather than the UTC instant.
+	///
+	/// Beware: cross-type comparisons are made against the UTC instant, so for
+	/// any non-zero offset, the result will _not_ equal the `LocalOffset` it
+	/// came from! Use `Utc2k::from(src.unixtime())` if you need the instant.
+	///
+	/// ```
+	/// use utc2k::{LocalOffset, Utc2k};
+	///
+	/// let utc = Utc2k::new(2020, 10, 15, 20, 25, 30);
+	/// let local = LocalOffset::from(utc).with_offset(3600);
+	/// assert_eq!(Utc2k::from(local), Utc2k::new(2020, 10, 15, 21, 25, 30));
+	///
+	/// // Not the same thing!
+	/// assert_ne!(local, Utc2k::from(local));
+	/// assert_eq!(local, Utc2k::from(local.unixtime()));
+	/// ```
 	fn from(src: LocalOffset) -> Self { Self::from(src.localtime()) }
 }
 
+impl PartialEq<Utc2k> for LocalOffset {
+	#[inline]
+	/// # Cross-Type Equality.
+	///
+	/// Comparisons against `Utc2k` and `FmtUtc2k` are made against the
+	/// underlying UTC timestamp — [`LocalOffset::unixtime`] — not the local
+	/// wall time, so the other side should hold an honest UTC datetime.
+	///
+	/// Note that the `From<LocalOffset>` conversions produce _local_ wall
+	/// times, so `local == Utc2k::from(local)` will be `false` for any
+	/// non-zero offset.
+	///
+	/// ```
+	/// use utc2k::{LocalOffset, Utc2k};
+	///
+	/// let utc = Utc2k::new(2020, 10, 15, 20, 25, 30);
+	/// let local = LocalOffset::from(utc);
+	/// assert_eq!(local, utc);
+	/// assert_eq!(utc, local);
+	/// ```
+	fn eq(&self, other: &Utc2k) -> bool { self.unixtime == other.unixtime() }
+}
+
+impl PartialEq<LocalOffset> for Utc2k {
+	#[inline]
+	fn eq(&self, other: &LocalOffset) -> bool { other == self }
+}
+
+impl PartialEq<FmtUtc2k> for LocalOffset {
+	#[inline]
+	fn eq(&self, other: &FmtUtc2k) -> bool { *self == Utc2k::from(*other) }
+}
 
+impl PartialEq<LocalOffset> for FmtUtc2k {
+	#[inline]
+	fn eq(&self, other: &LocalOffset) -> bool { other == self }
+}
 
+impl PartialOrd<Utc2k> for LocalOffset {
+	#[inline]
+	/// # Cross-Type Ordering.
+	///
+	/// As with equality, ordering is based on the UTC timestamp, _not_ the
+	/// local wall time returned by the `From<LocalOffset>` conversions.
+	///
+	/// ```
+	/// use utc2k::{LocalOffset, Utc2k};
+	///
+	/// let utc = Utc2k::new(2020, 10, 15, 20, 25, 30);
+	/// let local = LocalOffset::from(utc + 1);
+	/// assert!(utc < local);
+	/// assert!(local > utc);
+	/// ```
+	fn partial_cmp(&self, other: &Utc2k) -> Option<Ordering> {
+		Some(self.unixtime.cmp(&other.unixtime()))
+	}
+}
+
+impl PartialOrd<LocalOffset> for Utc2k {
+	#[inline]
+	fn partial_cmp(&self, other: &LocalOffset) -> Option<Ordering> {
+		other.partial_cmp(self).map(Ordering::reverse)
+	}
+}
+
+impl PartialOrd<FmtUtc2k> for LocalOffset {
+	#[inline]
+	fn partial_cmp(&self, other: &FmtUtc2k) -> Option<Ordering> {
+		self.partial_cmp(&Utc2k::from(*other))
+	}
+}
+
+impl PartialOrd<LocalOffset> for FmtUtc2k {
+	#[inline]
+	fn partial_cmp(&self, other: &LocalOffset) -> Option<Ordering> {
+		other.partial_cmp(self).map(Ordering::reverse)
+	}
+}
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "local")))]
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+/// # Offset Source.
+///
+/// This is returned by [`LocalOffset::source`] to indicate how trustworthy
+/// the offset is.
+pub enum OffsetSource {
+	/// # System.
+	///
+	/// The offset was determined from the system's timezone details.
+	System,
+
+	/// # Fixed.
+	///
+	/// The offset was set explicitly via [`LocalOffset::with_offset`].
+	Fixed,
+
+	#[default]
+	/// # None.
+	///
+	/// No timezone details could be found, so UTC was assumed.
+	None,
+}
+
+
+#[cfg(not(windows))]
 /// # Parsed Timezone Details.
-static TZ: OnceLock<TimeZone> = OnceLock::new();
+static TZ: OnceLock<Option<TimeZone>> = OnceLock::new();
 
 /// # Offset From Unixtime.
 ///
+/// Return the local offset for the given moment, or `None` if no offset
+/// could be determined.
+///
+/// (Tests can swap in a fake timezone via `tests::fixed_from_utc2k`.)
+fn offset(now: u32) -> Option<i32> {
+	#[cfg(test)]
+	if let Some(tzi) = tests::ZONE.get() { return Some(tzi.offset(now)); }
+
+	system_offset(now)
+}
+
+#[cfg(not(windows))]
+/// # System Offset From Unixtime.
+///
 /// The local timezone details are cached on the first run; subsequent method
 /// calls will perform much faster.
-fn offset(now: u32) -> i32 {
-	TZ.get_or_init(|| TimeZone::local().unwrap_or_else(|_| TimeZone::utc()))
+///
+/// `None` is returned if no offset could be determined.
+fn system_offset(now: u32) -> Option<i32> {
+	TZ.get_or_init(|| TimeZone::local().ok())
+		.as_ref()?
 		.find_local_time_type(i64::from(now))
-		.map_or(0, LocalTimeType::ut_offset)
+		.ok()
+		.map(LocalTimeType::ut_offset)
+}
+
+#[cfg(windows)]
+#[expect(unsafe_code, reason = "Required for FFI.")]
+/// # System Offset From Unixtime.
+///
+/// Windows doesn't keep a tzdata database lying around, but it will tell us
+/// the standard/daylight rules for any given year, which is all we need.
+///
+/// `None` is returned if no offset could be determined.
+fn system_offset(now: u32) -> Option<i32> {
+	#[link(name = "kernel32")]
+	extern "system" {
+		fn GetTimeZoneInformationForYear(
+			year: u16,
+			dynamic: *const std::ffi::c_void,
+			tzi: *mut TimeZoneInformation,
+		) -> i32;
+	}
+
+	let mut tzi = TimeZoneInformation::default();
+	let year = Utc2k::from(now).year();
+
+	// Safety: a null dynamic pointer tells Windows to use the current
+	// timezone, and the output struct is correctly sized and aligned.
+	let res = unsafe {
+		GetTimeZoneInformationForYear(year, std::ptr::null(), &mut tzi)
+	};
+
+	if res == 0 { None }
+	else { Some(tzi.offset(now)) }
+}
+
+
+
+/// # Local Midnight.
+///
+/// Return the UTC timestamp corresponding to the start of the local day
+/// containing `now`, using `cb` to look up the offset for a given moment.
+///
+/// See [`local_to_utc`] for details about DST handling.
+fn local_midnight<F>(now: u32, cb: F) -> u32
+where F: Fn(u32) -> Option<i32> {
+	let local = i64::from(now) + i64::from(cb(now).unwrap_or(0));
+	let midnight = local - local.rem_euclid(i64::from(crate::DAY_IN_SECONDS));
+	local_to_utc(midnight, now, cb)
+}
+
+/// # Local to UTC.
+///
+/// Return the UTC timestamp corresponding to the `local` wall-clock time,
+/// using `cb` to look up the offset for a given moment, and the offset at
+/// `hint` as the first guess.
+///
+/// The offset at the target can differ from the offset at `hint` if a DST
+/// transition happened in between, so the first guess is refined once. If
+/// the refinement is inconsistent, the target fell in a gap, and the later
+/// candidate is used instead, effectively pushing the time forward by the
+/// length of the gap. (When the gap begins at the target, as with midnight
+/// in some zones, that's the transition itself.) Ambiguous times — those
+/// occurring twice when the clocks fall back — keep the `hint` offset.
+fn local_to_utc<F>(local: i64, hint: u32, cb: F) -> u32
+where F: Fn(u32) -> Option<i32> {
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	#[expect(clippy::cast_sign_loss, reason = "False positive.")]
+	/// # Clamp to `u32`.
+	const fn clamp(src: i64) -> u32 {
+		if src <= 0 { 0 }
+		else if src >= u32::MAX as i64 { u32::MAX }
+		else { src as u32 }
+	}
+
+	let off1 = cb(hint).unwrap_or(0);
+	let c1 = clamp(local - i64::from(off1));
+	let off2 = cb(c1).unwrap_or(0);
+	if off2 == off1 { return c1; }
+
+	let c2 = clamp(local - i64::from(off2));
+	if cb(c2).unwrap_or(0) == off2 { c2 }
+	else { c1.max(c2) }
+}
+
+
+
+#[cfg(any(windows, test))]
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(C)]
+/// # System Time.
+///
+/// This mirrors the Windows `SYSTEMTIME` struct, which is used to describe
+/// the standard/daylight transition rules.
+struct SystemTime {
+	/// # Year.
+	///
+	/// Zero for relative transitions.
+	year: u16,
+
+	/// # Month.
+	///
+	/// Zero if there are no transitions.
+	month: u16,
+
+	/// # Weekday.
+	///
+	/// Zero is Sunday.
+	weekday: u16,
+
+	/// # Day.
+	///
+	/// For relative transitions, this is the weekday occurrence (`1..=5`),
+	/// with five meaning "the last".
+	day: u16,
+
+	/// # Hour.
+	hour: u16,
+
+	/// # Minute.
+	minute: u16,
+
+	/// # Second.
+	second: u16,
+
+	/// # Millisecond.
+	millisecond: u16,
+}
+
+#[cfg(any(windows, test))]
+impl SystemTime {
+	/// # Transition (Local Seconds).
+	///
+	/// Return the (local) "unixtime" this transition occurs at for the given
+	/// year, or `None` if there isn't one.
+	fn transition(&self, y: u16) -> Option<i64> {
+		let m = u8::try_from(self.month).ok().filter(|m| (1..=12).contains(m))?;
+		let d = u8::try_from(self.day).ok()?;
+		let d =
+			if self.year == 0 {
+				let weekday = crate::Weekday::from(u8::try_from(self.weekday).ok()?.checked_add(1)?);
+				match d {
+					1..=4 => weekday.nth_in_month(y, m, d)?,
+					5 => weekday.last_in_month(y, m)?,
+					_ => return None,
+				}
+			}
+			else { d };
+
+		let base = i64::from(Utc2k::new(y, m, d, 0, 0, 0).unixtime());
+		Some(
+			base +
+			i64::from(self.hour) * i64::from(crate::HOUR_IN_SECONDS) +
+			i64::from(self.minute) * i64::from(crate::MINUTE_IN_SECONDS) +
+			i64::from(self.second)
+		)
+	}
+}
+
+#[cfg(any(windows, test))]
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(C)]
+/// # Timezone Information.
+///
+/// This mirrors the Windows `TIME_ZONE_INFORMATION` struct.
+struct TimeZoneInformation {
+	/// # Bias (Minutes).
+	///
+	/// Note: UTC = local + bias.
+	bias: i32,
+
+	/// # Standard Name.
+	standard_name: [u16; 32],
+
+	/// # Standard Date.
+	///
+	/// The transition from daylight to standard time.
+	standard_date: SystemTime,
+
+	/// # Standard Bias (Minutes).
+	standard_bias: i32,
+
+	/// # Daylight Name.
+	daylight_name: [u16; 32],
+
+	/// # Daylight Date.
+	///
+	/// The transition from standard to daylight time.
+	daylight_date: SystemTime,
+
+	/// # Daylight Bias (Minutes).
+	daylight_bias: i32,
+}
+
+#[cfg(any(windows, test))]
+impl TimeZoneInformation {
+	/// # Offset.
+	///
+	/// Return the offset (in seconds) for the given unixtime.
+	fn offset(&self, now: u32) -> i32 {
+		let std_offset = (self.bias + self.standard_bias).saturating_mul(-60);
+		let year = Utc2k::from(now).year();
+
+		// No DST to worry about.
+		let (Some(start), Some(end)) = (
+			self.daylight_date.transition(year),
+			self.standard_date.transition(year),
+		) else { return std_offset; };
+
+		// DST begins in local standard time and ends in local daylight time;
+		// shift each to UTC so we can compare against now.
+		let dst_offset = (self.bias + self.daylight_bias).saturating_mul(-60);
+		let start = start - i64::from(std_offset);
+		let end = end - i64::from(dst_offset);
+		let now = i64::from(now);
+
+		let dst =
+			// Northern hemisphere.
+			if start < end { start <= now && now < end }
+			// Southern hemisphere.
+			else { now < end || start <= now };
+
+		if dst { dst_offset }
+		else { std_offset }
+	}
 }
 
 
@@ -216,12 +861,108 @@ fn offset(now: u32) -> i32 {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use std::cell::Cell;
+
+	thread_local! {
+		/// # Fake Timezone.
+		///
+		/// When set, this replaces the system timezone for the current
+		/// thread.
+		pub(super) static ZONE: Cell<Option<TimeZoneInformation>> = const { Cell::new(None) };
+	}
+
+	/// # Fixed From `Utc2k`.
+	///
+	/// Replace the current thread's timezone with `tzi` — for this and all
+	/// subsequent lookups — and return the `LocalOffset` for `src`.
+	fn fixed_from_utc2k(tzi: TimeZoneInformation, src: Utc2k) -> LocalOffset {
+		ZONE.set(Some(tzi));
+		LocalOffset::from(src)
+	}
 
 	#[test]
 	fn neg() {
 		let off = LocalOffset::now();
 		let off2 = -off;
 		assert_eq!(off, -off2); // We should be back to the original.
+		assert_eq!(off.offset(), (-off2).offset());
+	}
+
+	#[test]
+	fn cross_cmp() {
+		let mut rng = fastrand::Rng::new();
+		for _ in 0..1000 {
+			let a = Utc2k::from(rng.u32(Utc2k::MIN_UNIXTIME..=Utc2k::MAX_UNIXTIME));
+			let b = Utc2k::from(rng.u32(Utc2k::MIN_UNIXTIME..=Utc2k::MAX_UNIXTIME));
+			let a_fmt = FmtUtc2k::from(a);
+			let b_fmt = FmtUtc2k::from(b);
+			let a_local = LocalOffset::from(a);
+			let b_local = LocalOffset::from(b);
+
+			// Self-equality, every direction.
+			assert_eq!(a_local, a);
+			assert_eq!(a, a_local);
+			assert_eq!(a_local, a_fmt);
+			assert_eq!(a_fmt, a_local);
+
+			// Orderings should match the native one.
+			let expected = a.cmp(&b);
+			assert_eq!(a_local.partial_cmp(&b), Some(expected));
+			assert_eq!(a.partial_cmp(&b_local), Some(expected));
+			assert_eq!(a_local.partial_cmp(&b_fmt), Some(expected));
+			assert_eq!(a_fmt.partial_cmp(&b_local), Some(expected));
+			assert_eq!(b_local.partial_cmp(&a), Some(expected.reverse()));
+			assert_eq!(b_fmt.partial_cmp(&a_local), Some(expected.reverse()));
+			assert_eq!(a_local == b, a == b);
+			assert_eq!(b_fmt == a_local, a == b);
+
+			// The wall-time conversions are not the same instant, unless the
+			// offset is zero.
+			let offset = rng.i32(-86_399..=86_399);
+			let fixed = a_local.with_offset(offset);
+			let wall = Utc2k::from(fixed);
+			assert_eq!(fixed, a);
+			assert_eq!(fixed == wall, wall == a);
+			assert_eq!(fixed == FmtUtc2k::from(fixed), wall == a);
+			assert_eq!(fixed.partial_cmp(&wall), a.partial_cmp(&wall));
+			if offset == 0 { assert_eq!(fixed, wall); }
+			else if a != Utc2k::MIN && a != Utc2k::MAX { assert_ne!(fixed, wall); }
+		}
+	}
+
+	#[test]
+	fn eq_hash() {
+		use std::hash::DefaultHasher;
+
+		fn hash<T: Hash>(src: &T) -> u64 {
+			let mut h = DefaultHasher::new();
+			src.hash(&mut h);
+			h.finish()
+		}
+
+		let mut rng = fastrand::Rng::new();
+		for _ in 0..1000 {
+			let utc = Utc2k::from(rng.u32(Utc2k::MIN_UNIXTIME..=Utc2k::MAX_UNIXTIME));
+			let system = LocalOffset::from(utc);
+			let fixed = system.with_offset(rng.i32(-86_399..=86_399));
+			assert_eq!(fixed.source(), OffsetSource::Fixed);
+
+			// Same instant, same value.
+			assert_eq!(system, fixed);
+			assert_eq!(fixed, system);
+			assert_eq!(hash(&system), hash(&fixed));
+			assert_eq!(-system, system);
+			assert_eq!(hash(&-system), hash(&system));
+
+			// And consistent with the cross-type comparisons.
+			assert_eq!(system, utc);
+			assert_eq!(fixed, utc);
+
+			// Different instants should still differ.
+			if utc != Utc2k::MAX {
+				assert_ne!(system, LocalOffset::from(utc + 1));
+			}
+		}
 	}
 
 	#[test]
@@ -231,4 +972,246 @@ mod tests {
 		let now = crate::unixtime();
 		assert_eq!(LocalOffset::now().offset, LocalOffset::from(now).offset);
 	}
+
+	#[test]
+	fn now_local_wall() {
+		// Unless the clock ticked over in between, these should match.
+		let off = LocalOffset::now();
+		let wall = Utc2k::now_local_wall();
+		assert!(Utc2k::from(off).abs_diff(wall) <= 1);
+
+		// The timestamp is shifted by the offset (barring saturation).
+		let utc = Utc2k::from(off.unixtime());
+		if utc != Utc2k::MIN && utc != Utc2k::MAX {
+			let expected = i64::from(off.unixtime()) + i64::from(off.offset());
+			assert!(expected.abs_diff(i64::from(wall.unixtime())) <= 1);
+		}
+	}
+
+	#[test]
+	fn checked() {
+		for date in [Utc2k::MIN, Utc2k::now(), Utc2k::MAX] {
+			let off = LocalOffset::from(date);
+			match LocalOffset::checked_from_utc2k(date) {
+				Ok(off2) => {
+					assert_eq!(off, off2);
+					assert_eq!(off.source(), OffsetSource::System);
+				},
+				Err(Utc2kError::NoTimezone) => assert_eq!(off.source(), OffsetSource::None),
+				Err(Utc2kError::Underflow) => assert!(date == Utc2k::MIN && off.offset() < 0),
+				Err(Utc2kError::Overflow) => assert!(date == Utc2k::MAX && 0 < off.offset()),
+				Err(e) => panic!("Unexpected error: {e}"),
+			}
+		}
+	}
+
+	#[test]
+	fn with_offset() {
+		let mut rng = fastrand::Rng::new();
+		for _ in 0..10_000 {
+			let utc = Utc2k::from(rng.u32(Utc2k::MIN_UNIXTIME..=Utc2k::MAX_UNIXTIME));
+			let a = rng.i32(-86_399..=86_399);
+			let b = rng.i32(-86_399..=86_399);
+
+			let off_a = LocalOffset::from(utc).with_offset(a);
+			let off_b = off_a.with_offset(b);
+			assert_eq!(off_a.unixtime(), utc.unixtime());
+			assert_eq!(off_b.unixtime(), utc.unixtime());
+			assert_eq!(off_a.offset(), a);
+			assert_eq!(off_b.offset(), b);
+			assert_eq!(off_b.source(), OffsetSource::Fixed);
+
+			// The wall time should be shifted (and saturated) accordingly.
+			let wall = (i64::from(utc.unixtime()) + i64::from(b))
+				.clamp(i64::from(Utc2k::MIN_UNIXTIME), i64::from(Utc2k::MAX_UNIXTIME));
+			assert_eq!(i64::from(Utc2k::from(off_b).unixtime()), wall);
+		}
+
+		// The fixed offset should survive local calendar operations.
+		let utc = Utc2k::new(2025, 6, 15, 12, 30, 0);
+		let ist = LocalOffset::from(utc).with_offset(19_800);
+		for (then, expected) in [
+			(ist.with_time(9, 0, 0), Utc2k::new(2025, 6, 15, 3, 30, 0)),
+			(ist.add_days(1), Utc2k::new(2025, 6, 16, 12, 30, 0)),
+			(ist.add_days(200).with_time(23, 45, 0), Utc2k::new(2026, 1, 1, 18, 15, 0)),
+		] {
+			assert_eq!(then.offset(), 19_800);
+			assert_eq!(then.source(), OffsetSource::Fixed);
+			assert_eq!(Utc2k::from(then.unixtime()), expected);
+			assert_eq!(Utc2k::from(then) - 19_800, expected);
+		}
+	}
+
+	#[test]
+	fn today() {
+		// The real thing should land on the local day.
+		let today = LocalOffset::today();
+		assert_eq!(
+			Utc2k::from(today).to_midnight(),
+			Utc2k::from(LocalOffset::now()).to_midnight(),
+		);
+		assert_eq!(LocalOffset::today_utc(), Utc2k::from(today.unixtime()));
+
+		// US Eastern, with transitions at 2AM.
+		let tzi = TimeZoneInformation {
+			bias: 300,
+			standard_date: SystemTime { month: 11, day: 1, hour: 2, ..SystemTime::default() },
+			daylight_date: SystemTime { month: 3, day: 2, hour: 2, ..SystemTime::default() },
+			daylight_bias: -60,
+			..TimeZoneInformation::default()
+		};
+		let cb = |t| Some(tzi.offset(t));
+		for (now, expected) in [
+			("2024-01-15 12:00:00", "2024-01-15 05:00:00"),
+			("2024-01-15 03:00:00", "2024-01-14 05:00:00"),
+			// Spring forward; midnight was still standard time.
+			("2024-03-10 12:00:00", "2024-03-10 05:00:00"),
+			// Fall back; midnight was still daylight time.
+			("2024-11-03 12:00:00", "2024-11-03 04:00:00"),
+			("2024-07-04 12:00:00", "2024-07-04 04:00:00"),
+		] {
+			let now = Utc2k::try_from(now).unwrap().unixtime();
+			assert_eq!(Utc2k::from(local_midnight(now, cb)).to_string(), expected);
+		}
+
+		// A Brazil-style zone with transitions at midnight.
+		let tzi = TimeZoneInformation {
+			bias: 180,
+			standard_date: SystemTime { month: 2, day: 3, hour: 0, ..SystemTime::default() },
+			daylight_date: SystemTime { month: 10, day: 1, hour: 0, ..SystemTime::default() },
+			daylight_bias: -60,
+			..TimeZoneInformation::default()
+		};
+		let cb = |t| Some(tzi.offset(t));
+		for (now, expected) in [
+			// Midnight doesn't exist; the day starts at 01:00 local.
+			("2018-10-07 15:00:00", "2018-10-07 03:00:00"),
+			// Midnight happens after the fall back.
+			("2018-02-18 15:00:00", "2018-02-18 03:00:00"),
+			("2018-07-04 12:00:00", "2018-07-04 03:00:00"),
+			("2018-12-25 12:00:00", "2018-12-25 02:00:00"),
+		] {
+			let now = Utc2k::try_from(now).unwrap().unixtime();
+			assert_eq!(Utc2k::from(local_midnight(now, cb)).to_string(), expected);
+		}
+
+		// No offset at all.
+		let now = Utc2k::new(2024, 5, 5, 13, 14, 15);
+		assert_eq!(
+			local_midnight(now.unixtime(), |_| None),
+			now.to_midnight().unixtime(),
+		);
+	}
+
+	#[test]
+	fn local_calendar() {
+		/// # Helper: Resolve Via the Fake Timezone.
+		fn resolve(tzi: TimeZoneInformation, now: &str, f: fn(LocalOffset) -> LocalOffset) -> String {
+			let now = fixed_from_utc2k(tzi, Utc2k::try_from(now).unwrap());
+			let then = f(now);
+			assert_eq!(then.source(), OffsetSource::System);
+			assert_eq!(then.offset(), tzi.offset(then.unixtime()));
+			Utc2k::from(then.unixtime()).to_string()
+		}
+
+		// The real thing should at least land on the right local date/time.
+		let now = LocalOffset::now();
+		let then = now.add_days(1).with_time(9, 0, 0);
+		let local = Utc2k::from(then);
+		assert_eq!(local.to_midnight(), Utc2k::from(now).to_midnight().add_days(1));
+
+		// US Eastern, with transitions at 2AM.
+		let tzi = TimeZoneInformation {
+			bias: 300,
+			standard_date: SystemTime { month: 11, day: 1, hour: 2, ..SystemTime::default() },
+			daylight_date: SystemTime { month: 3, day: 2, hour: 2, ..SystemTime::default() },
+			daylight_bias: -60,
+			..TimeZoneInformation::default()
+		};
+
+		// Same day, same offset.
+		assert_eq!(resolve(tzi, "2024-01-15 17:00:00", |d| d.with_time(9, 0, 0)), "2024-01-15 14:00:00");
+		assert_eq!(resolve(tzi, "2024-07-04 17:00:00", |d| d.with_time(9, 0, 0)), "2024-07-04 13:00:00");
+
+		// Same day, different offsets.
+		assert_eq!(resolve(tzi, "2024-03-10 05:00:00", |d| d.with_time(9, 0, 0)), "2024-03-10 13:00:00");
+		assert_eq!(resolve(tzi, "2024-11-03 04:00:00", |d| d.with_time(9, 0, 0)), "2024-11-03 14:00:00");
+
+		// Spring forward: 9AM EST becomes 9AM EDT.
+		assert_eq!(resolve(tzi, "2024-03-09 14:00:00", |d| d.add_days(1)), "2024-03-10 13:00:00");
+
+		// Fall back: 9AM EDT becomes 9AM EST.
+		assert_eq!(resolve(tzi, "2024-11-02 13:00:00", |d| d.add_days(1)), "2024-11-03 14:00:00");
+
+		// Both at once.
+		assert_eq!(
+			resolve(tzi, "2024-03-09 14:00:00", |d| d.add_days(239).with_time(12, 0, 0)),
+			"2024-11-03 17:00:00",
+		);
+
+		// Nonexistent: 2:30AM on spring-forward day is pushed ahead to 3:30.
+		assert_eq!(
+			resolve(tzi, "2024-03-10 05:00:00", |d| d.with_time(2, 30, 0)),
+			"2024-03-10 07:30:00",
+		);
+
+		// Ambiguous: 1:30AM on fall-back day keeps the original offset.
+		assert_eq!(
+			resolve(tzi, "2024-11-03 04:00:00", |d| d.with_time(1, 30, 0)),
+			"2024-11-03 05:30:00",
+		);
+		assert_eq!(
+			resolve(tzi, "2024-11-03 15:00:00", |d| d.with_time(1, 30, 0)),
+			"2024-11-03 06:30:00",
+		);
+	}
+
+	#[test]
+	fn windows_rules() {
+		// US Eastern: DST from the second Sunday in March to the first Sunday
+		// in November, both at 2AM.
+		let tzi = TimeZoneInformation {
+			bias: 300,
+			standard_date: SystemTime { month: 11, day: 1, hour: 2, ..SystemTime::default() },
+			daylight_date: SystemTime { month: 3, day: 2, hour: 2, ..SystemTime::default() },
+			daylight_bias: -60,
+			..TimeZoneInformation::default()
+		};
+		for (date, expected) in [
+			("2024-01-15 12:00:00", -18_000),
+			("2024-03-10 06:59:59", -18_000),
+			("2024-03-10 07:00:00", -14_400),
+			("2024-07-04 12:00:00", -14_400),
+			("2024-11-03 05:59:59", -14_400),
+			("2024-11-03 06:00:00", -18_000),
+		] {
+			let now = Utc2k::try_from(date).unwrap().unixtime();
+			assert_eq!(tzi.offset(now), expected, "{date}");
+		}
+
+		// Sydney: DST from the first Sunday in October to the first Sunday
+		// in April.
+		let tzi = TimeZoneInformation {
+			bias: -600,
+			standard_date: SystemTime { month: 4, day: 1, hour: 3, ..SystemTime::default() },
+			daylight_date: SystemTime { month: 10, day: 1, hour: 2, ..SystemTime::default() },
+			daylight_bias: -60,
+			..TimeZoneInformation::default()
+		};
+		for (date, expected) in [
+			("2024-01-15 12:00:00", 39_600),
+			("2024-04-06 15:59:59", 39_600),
+			("2024-04-06 16:00:00", 36_000),
+			("2024-07-04 12:00:00", 36_000),
+			("2024-10-05 15:59:59", 36_000),
+			("2024-10-05 16:00:00", 39_600),
+		] {
+			let now = Utc2k::try_from(date).unwrap().unixtime();
+			assert_eq!(tzi.offset(now), expected, "{date}");
+		}
+
+		// No DST at all.
+		let tzi = TimeZoneInformation { bias: -330, ..TimeZoneInformation::default() };
+		assert_eq!(tzi.offset(1_720_000_000), 19_800);
+	}
 }
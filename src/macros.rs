@@ -23,7 +23,7 @@ macro_rules! display_str {
 		impl ::std::fmt::Display for $ty {
 			#[inline]
 			fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-				f.write_str(self.$cast())
+				f.pad(self.$cast())
 			}
 		}
 	);
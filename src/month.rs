@@ -3,12 +3,16 @@
 */
 
 use crate::{
+	DAY_IN_SECONDS,
 	macros,
 	Utc2k,
 	Utc2kError,
+	Weekday,
+	YEAR_IN_SECONDS,
 };
 use std::{
 	cmp::Ordering,
+	iter::FusedIterator,
 	ops::{
 		Add,
 		AddAssign,
@@ -297,6 +301,37 @@ impl Month {
 	/// ```
 	pub fn now() -> Self { Self::from(Utc2k::now()) }
 
+	#[must_use]
+	/// # Try From U8.
+	///
+	/// Return the `Month` corresponding to the given number, or `None` if it
+	/// is not between `1..=12`.
+	///
+	/// Unlike the `From<u8>` implementation — which wraps out-of-range values
+	/// around to keep arithmetic simple — this is strict, making it better
+	/// suited for validation.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::Month;
+	///
+	/// assert_eq!(Month::try_from_u8(1), Some(Month::January));
+	/// assert_eq!(Month::try_from_u8(12), Some(Month::December));
+	///
+	/// // Strict.
+	/// assert_eq!(Month::try_from_u8(0), None);
+	/// assert_eq!(Month::try_from_u8(13), None);
+	///
+	/// // Versus wrapping.
+	/// assert_eq!(Month::from(0_u8), Month::December);
+	/// assert_eq!(Month::from(13_u8), Month::January);
+	/// ```
+	pub const fn try_from_u8(src: u8) -> Option<Self> {
+		if 0 < src && src < 13 { Some(Self::from_u8(src)) }
+		else { None }
+	}
+
 	/// # From Abbreviation Bytes.
 	///
 	/// This matches the first three non-whitespace bytes, case-insensitively,
@@ -394,6 +429,78 @@ impl Month {
 		]
 	}
 
+	#[must_use]
+	/// # Range.
+	///
+	/// Return an iterator over the months from `self` to `end`, inclusive,
+	/// wrapping around if `end` comes before `self`.
+	///
+	/// Unlike the endless [`Month::into_iter`], this iterator stops once it has
+	/// reached `end`. It can also be run in reverse, and knows its length.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::Month;
+	///
+	/// let range = Month::November.range(Month::February);
+	/// assert_eq!(range.len(), 4);
+	/// assert_eq!(
+	///     range.collect::<Vec<_>>(),
+	///     [Month::November, Month::December, Month::January, Month::February],
+	/// );
+	///
+	/// // A range can also be a single month.
+	/// assert_eq!(
+	///     Month::November.range(Month::November).collect::<Vec<_>>(),
+	///     [Month::November],
+	/// );
+	/// ```
+	pub const fn range(self, end: Self) -> RangedMonthIter {
+		let start = self as u8;
+		let end = end as u8;
+		RangedMonthIter {
+			start: self,
+			front: 0,
+			back:
+				if start <= end { end - start + 1 }
+				else { 13 - start + end },
+		}
+	}
+
+	#[must_use]
+	/// # Range (Non-Wrapping).
+	///
+	/// Return an iterator over the months from `self` to `end`, inclusive.
+	///
+	/// Unlike [`Month::range`], this never wraps around; if `end` comes before
+	/// `self`, the iterator will simply be empty.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::Month;
+	///
+	/// assert_eq!(
+	///     Month::January.range_bounded(Month::March).collect::<Vec<_>>(),
+	///     [Month::January, Month::February, Month::March],
+	/// );
+	///
+	/// // Backwards ranges are empty.
+	/// assert_eq!(Month::November.range_bounded(Month::February).len(), 0);
+	/// ```
+	pub const fn range_bounded(self, end: Self) -> RangedMonthIter {
+		let start = self as u8;
+		let end = end as u8;
+		RangedMonthIter {
+			start: self,
+			front: 0,
+			back:
+				if start <= end { end - start + 1 }
+				else { 0 },
+		}
+	}
+
 	#[must_use]
 	/// # Month Size (Days).
 	///
@@ -427,6 +534,251 @@ impl Month {
 		}
 	}
 
+	#[must_use]
+	/// # Weekday of First.
+	///
+	/// Return the [`Weekday`] this month begins on in the given year, or
+	/// `None` if the year is outside `2000..=2099`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::{Month, Weekday};
+	///
+	/// assert_eq!(
+	///     Month::October.weekday_of_first(2023),
+	///     Some(Weekday::Sunday),
+	/// );
+	/// assert_eq!(Month::January.weekday_of_first(2100), None);
+	/// ```
+	pub fn weekday_of_first(self, y: u16) -> Option<Weekday> {
+		if (2000..=2099).contains(&y) { Some(self.first_day(y).weekday()) }
+		else { None }
+	}
+
+	#[must_use]
+	/// # Ordinal Seconds.
+	///
+	/// Return the number of seconds elapsed between the start of the year and
+	/// the start of this month.
+	///
+	/// Because leap days fall at the end of February, every month from March
+	/// onward is pushed back an extra day in leap years; set `leap` to `true`
+	/// to account for that.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::Month;
+	///
+	/// assert_eq!(Month::January.ordinal_seconds(false), 0);
+	/// assert_eq!(Month::February.ordinal_seconds(false), 31 * 86_400);
+	///
+	/// // Leap years only affect March and later.
+	/// assert_eq!(Month::February.ordinal_seconds(true), 31 * 86_400);
+	/// assert_eq!(Month::March.ordinal_seconds(false), 59 * 86_400);
+	/// assert_eq!(Month::March.ordinal_seconds(true), 60 * 86_400);
+	/// ```
+	pub const fn ordinal_seconds(self, leap: bool) -> u32 {
+		let days = match self {
+			Self::January => 0,
+			Self::February => 31,
+			Self::March => 59,
+			Self::April => 90,
+			Self::May => 120,
+			Self::June => 151,
+			Self::July => 181,
+			Self::August => 212,
+			Self::September => 243,
+			Self::October => 273,
+			Self::November => 304,
+			Self::December => 334,
+		};
+
+		if leap && 2 < self as u8 { (days + 1) * DAY_IN_SECONDS }
+		else { days * DAY_IN_SECONDS }
+	}
+
+	#[must_use]
+	/// # From Ordinal Seconds.
+	///
+	/// This is the reverse of [`Month::ordinal_seconds`]: given the number of
+	/// seconds elapsed since the start of a year, return the corresponding
+	/// month along with the number of seconds remaining within it.
+	///
+	/// As with [`Month::ordinal_seconds`], `leap` should be `true` for leap
+	/// years so that February gets its extra day.
+	///
+	/// `None` is returned if the seconds exceed the length of the year.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::Month;
+	///
+	/// // Noon on January 2nd.
+	/// assert_eq!(
+	///     Month::try_from_ordinal_seconds(86_400 + 43_200, false),
+	///     Some((Month::January, 86_400 + 43_200)),
+	/// );
+	///
+	/// // Day sixty is March 1st in regular years, but February 29th in leap
+	/// // years.
+	/// assert_eq!(
+	///     Month::try_from_ordinal_seconds(59 * 86_400, false),
+	///     Some((Month::March, 0)),
+	/// );
+	/// assert_eq!(
+	///     Month::try_from_ordinal_seconds(59 * 86_400, true),
+	///     Some((Month::February, 28 * 86_400)),
+	/// );
+	///
+	/// // Too big!
+	/// assert_eq!(Month::try_from_ordinal_seconds(365 * 86_400, false), None);
+	/// assert!(Month::try_from_ordinal_seconds(365 * 86_400, true).is_some());
+	/// ```
+	pub const fn try_from_ordinal_seconds(secs: u32, leap: bool) -> Option<(Self, u32)> {
+		let year = if leap { YEAR_IN_SECONDS + DAY_IN_SECONDS } else { YEAR_IN_SECONDS };
+		if year <= secs { return None; }
+
+		let all = Self::all();
+		let mut idx = all.len();
+		while 0 < idx {
+			idx -= 1;
+			let start = all[idx].ordinal_seconds(leap);
+			if start <= secs { return Some((all[idx], secs - start)); }
+		}
+
+		// January starts at zero so we can't actually get here.
+		None
+	}
+
+	#[inline]
+	#[must_use]
+	/// # First Day.
+	///
+	/// Return a [`Utc2k`] representing midnight on the first day of this
+	/// month for the given year.
+	///
+	/// As with [`Utc2k::from_ymd`], years prior to 2000 or after 2099 will be
+	/// saturated to fit.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::{Month, Utc2k};
+	///
+	/// assert_eq!(
+	///     Month::June.first_day(2025),
+	///     Utc2k::from_ymd(2025, 6, 1),
+	/// );
+	/// ```
+	pub fn first_day(self, y: u16) -> Utc2k { Utc2k::from_ymd(y, self as u8, 1) }
+
+	#[must_use]
+	/// # Overflowing Add.
+	///
+	/// Add `n` months, returning the new month along with the number of
+	/// years carried over.
+	///
+	/// This is equivalent to the wrapping `Add` implementations, but doesn't
+	/// discard the year carry.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::Month;
+	///
+	/// assert_eq!(Month::January.overflowing_add(1), (Month::February, 0));
+	/// assert_eq!(Month::December.overflowing_add(1), (Month::January, 1));
+	/// assert_eq!(Month::June.overflowing_add(30), (Month::December, 2));
+	/// ```
+	pub const fn overflowing_add(self, n: u32) -> (Self, u32) {
+		let idx = self as u32 - 1 + n % 12;
+		(Self::from_u8((idx % 12) as u8 + 1), n / 12 + idx / 12)
+	}
+
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	#[must_use]
+	/// # Overflowing Sub.
+	///
+	/// Subtract `n` months, returning the new month along with the number of
+	/// years borrowed.
+	///
+	/// This is equivalent to the wrapping `Sub` implementations, but doesn't
+	/// discard the year borrow.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::Month;
+	///
+	/// assert_eq!(Month::February.overflowing_sub(1), (Month::January, 0));
+	/// assert_eq!(Month::January.overflowing_sub(1), (Month::December, 1));
+	/// assert_eq!(Month::June.overflowing_sub(30), (Month::December, 3));
+	/// ```
+	pub const fn overflowing_sub(self, n: u32) -> (Self, u32) {
+		let idx = self as u32 - 1;
+		let rem = n % 12;
+		if rem <= idx { (Self::from_u8((idx - rem) as u8 + 1), n / 12) }
+		else { (Self::from_u8((idx + 12 - rem) as u8 + 1), n / 12 + 1) }
+	}
+
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	#[must_use]
+	/// # Add to Year.
+	///
+	/// Add `n` months to this month of year `y`, returning the new year and
+	/// month, or `None` if the year is or would end up outside `2000..=2099`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::Month;
+	///
+	/// assert_eq!(
+	///     Month::November.add_to_year(2024, 3),
+	///     Some((2025, Month::February)),
+	/// );
+	///
+	/// // Too big!
+	/// assert_eq!(Month::December.add_to_year(2099, 1), None);
+	/// ```
+	pub const fn add_to_year(self, y: u16, n: u32) -> Option<(u16, Self)> {
+		if y < 2000 || 2099 < y { return None; }
+		let (m, carry) = self.overflowing_add(n);
+		if carry <= 2099 - y as u32 { Some((y + carry as u16, m)) }
+		else { None }
+	}
+
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	#[must_use]
+	/// # Subtract From Year.
+	///
+	/// Subtract `n` months from this month of year `y`, returning the new
+	/// year and month, or `None` if the year is or would end up outside
+	/// `2000..=2099`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::Month;
+	///
+	/// assert_eq!(
+	///     Month::February.sub_from_year(2025, 3),
+	///     Some((2024, Month::November)),
+	/// );
+	///
+	/// // Too small!
+	/// assert_eq!(Month::January.sub_from_year(2000, 1), None);
+	/// ```
+	pub const fn sub_from_year(self, y: u16, n: u32) -> Option<(u16, Self)> {
+		if y < 2000 || 2099 < y { return None; }
+		let (m, borrow) = self.overflowing_sub(n);
+		if borrow <= y as u32 - 2000 { Some((y - borrow as u16, m)) }
+		else { None }
+	}
+
 	#[must_use]
 	/// # As Str.
 	///
@@ -519,6 +871,62 @@ impl Iterator for RepeatingMonthIter {
 
 
 
+#[derive(Debug, Clone)]
+/// # Bounded Months.
+///
+/// This iterator yields each `Month` in a fixed range, once, as returned by
+/// [`Month::range`] or [`Month::range_bounded`].
+pub struct RangedMonthIter {
+	/// # First Month.
+	start: Month,
+
+	/// # Front Offset (From Start).
+	front: u8,
+
+	/// # Back Offset (From Start).
+	back: u8,
+}
+
+impl Iterator for RangedMonthIter {
+	type Item = Month;
+
+	/// # Next Month.
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.front < self.back {
+			let next = self.start + self.front;
+			self.front += 1;
+			Some(next)
+		}
+		else { None }
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl DoubleEndedIterator for RangedMonthIter {
+	/// # Previous Month.
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.front < self.back {
+			self.back -= 1;
+			Some(self.start + self.back)
+		}
+		else { None }
+	}
+}
+
+impl ExactSizeIterator for RangedMonthIter {
+	#[inline]
+	fn len(&self) -> usize { usize::from(self.back - self.front) }
+}
+
+impl FusedIterator for RangedMonthIter {}
+
+
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -546,6 +954,13 @@ mod tests {
 
 		assert_eq!(Month::from(0_u64), Month::December);
 
+		// Strict.
+		for i in 0..=u8::MAX {
+			let month = Month::try_from_u8(i);
+			if (1..=12).contains(&i) { assert_eq!(month, Some(Month::from(i))); }
+			else { assert!(month.is_none(), "Month {i} should be invalid."); }
+		}
+
 		let many: Vec<Month> = (1..=60_u32)
 			.map(Month::from)
 			.collect();
@@ -592,6 +1007,131 @@ mod tests {
 		}
 	}
 
+	#[test]
+	/// # Overflowing Math.
+	fn t_overflowing() {
+		for m in Month::all() {
+			let base = u32::from(m) - 1;
+			for n in 0..=1500_u32 {
+				// Compare against the naive total-month approach.
+				let (m2, carry) = m.overflowing_add(n);
+				assert_eq!(m2, m + n);
+				assert_eq!(carry, (base + n) / 12);
+				assert_eq!(u32::from(m2) - 1, (base + n) % 12);
+
+				let (m2, borrow) = m.overflowing_sub(n);
+				assert_eq!(m2, m - n);
+				assert_eq!(borrow, (n + 11 - base) / 12);
+
+				// Year-bounded.
+				for y in [2000_u16, 2050, 2099] {
+					let expected = u32::from(y) * 12 + base + n;
+					let expected = u16::try_from(expected / 12).ok()
+						.filter(|y| (2000..=2099).contains(y))
+						.map(|y| (y, Month::from(expected % 12 + 1)));
+					assert_eq!(m.add_to_year(y, n), expected);
+
+					let expected = (u32::from(y) * 12 + base).checked_sub(n);
+					let expected = expected.and_then(|e| u16::try_from(e / 12).ok()
+						.filter(|y| (2000..=2099).contains(y))
+						.map(|y| (y, Month::from(e % 12 + 1)))
+					);
+					assert_eq!(m.sub_from_year(y, n), expected);
+				}
+			}
+
+			// Extremes shouldn't overflow.
+			assert_eq!(m.overflowing_add(u32::MAX).0, m + u32::MAX);
+			assert_eq!(m.overflowing_sub(u32::MAX).0, m - u32::MAX);
+			assert!(m.add_to_year(2050, u32::MAX).is_none());
+			assert!(m.sub_from_year(2050, u32::MAX).is_none());
+			assert!(m.add_to_year(1999, 0).is_none());
+			assert!(m.sub_from_year(2100, 0).is_none());
+		}
+	}
+
+	#[test]
+	/// # Ordinal Seconds.
+	fn t_ordinal_seconds() {
+		for y in [2023_u16, 2024] {
+			let start = Utc2k::new(y, 1, 1, 0, 0, 0).unixtime();
+			let end = Utc2k::new(y + 1, 1, 1, 0, 0, 0).unixtime();
+			let leap = crate::is_leap_year(y);
+
+			for now in (start..end).step_by(1800) {
+				let date = Utc2k::from(now);
+				let month = date.month_enum();
+				let first = month.first_day(y).unixtime();
+				assert_eq!(month.ordinal_seconds(leap), first - start);
+				assert_eq!(
+					Month::try_from_ordinal_seconds(now - start, leap),
+					Some((month, now - first)),
+				);
+			}
+
+			assert_eq!(Month::try_from_ordinal_seconds(end - start, leap), None);
+		}
+	}
+
+	#[test]
+	/// # Bounded Ranges.
+	fn t_range() {
+		for start in Month::all() {
+			for (len, end) in (1..=12).zip(start) {
+				// Forward.
+				let range = start.range(end);
+				assert_eq!(range.len(), len);
+				let forward: Vec<Month> = range.collect();
+				assert_eq!(forward.len(), len);
+				assert_eq!(forward, start.into_iter().take(len).collect::<Vec<_>>());
+
+				// Backward.
+				let mut backward: Vec<Month> = start.range(end).rev().collect();
+				backward.reverse();
+				assert_eq!(forward, backward);
+
+				// Mixed.
+				let mut range = start.range(end);
+				assert_eq!(range.next(), Some(start));
+				if len == 1 { assert!(range.next_back().is_none()); }
+				else {
+					assert_eq!(range.next_back(), Some(end));
+					assert_eq!(range.len(), len - 2);
+				}
+			}
+		}
+
+		// Non-wrapping ranges should match the wrapping ones when in order,
+		// and be empty otherwise.
+		for start in Month::all() {
+			for end in Month::all() {
+				if start <= end {
+					assert_eq!(
+						start.range_bounded(end).collect::<Vec<_>>(),
+						start.range(end).collect::<Vec<_>>(),
+					);
+				}
+				else {
+					let mut range = start.range_bounded(end);
+					assert_eq!(range.len(), 0);
+					assert!(range.next().is_none());
+					assert!(range.next_back().is_none());
+				}
+			}
+		}
+
+		// Explicitly check a wrapping range.
+		let mut range = Month::all()[12 - 2].range(Month::all()[1]);
+		assert_eq!(range.len(), 4);
+		assert_eq!(range.next(), Some(Month::all()[12 - 2]));
+		assert_eq!(range.next_back(), Some(Month::all()[1]));
+		assert_eq!(range.next(), Some(Month::all()[12 - 1]));
+		assert_eq!(range.next_back(), Some(Month::all()[0]));
+		assert_eq!(range.len(), 0);
+		assert!(range.next().is_none());
+		assert!(range.next_back().is_none());
+	}
+
 	#[test]
 	/// # String Tests.
 	fn t_str() {
@@ -0,0 +1,88 @@
+/*!
+# UTC2K - Date Parts
+*/
+
+use crate::macros;
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Date Part.
+///
+/// This enum is used to identify an individual [`Utc2k`](crate::Utc2k)
+/// component by name, allowing for more dynamic, data-driven manipulations
+/// via [`Utc2k::get`](crate::Utc2k::get), [`Utc2k::with_part`](crate::Utc2k::with_part),
+/// and [`Utc2k::add_part`](crate::Utc2k::add_part).
+///
+/// ## Examples
+///
+/// ```
+/// use utc2k::{DatePart, Utc2k};
+///
+/// let date = Utc2k::new(2010, 5, 15, 16, 30, 1);
+/// assert_eq!(date.get(DatePart::Year), 2010);
+/// assert_eq!(date.get(DatePart::Minute), 30);
+/// ```
+pub enum DatePart {
+	/// # Year.
+	Year,
+
+	/// # Month.
+	Month,
+
+	/// # Day.
+	Day,
+
+	/// # Hour.
+	Hour,
+
+	/// # Minute.
+	Minute,
+
+	/// # Second.
+	Second,
+}
+
+macros::as_ref_borrow_cast!(DatePart: as_str str);
+macros::display_str!(as_str DatePart);
+
+impl DatePart {
+	#[must_use]
+	/// # All Parts.
+	///
+	/// Return an array containing all possible parts, from largest to
+	/// smallest.
+	pub const fn all() -> [Self; 6] {
+		[
+			Self::Year,
+			Self::Month,
+			Self::Day,
+			Self::Hour,
+			Self::Minute,
+			Self::Second,
+		]
+	}
+
+	#[must_use]
+	/// # As Str.
+	///
+	/// Return the part name as a string slice.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use utc2k::DatePart;
+	///
+	/// assert_eq!(DatePart::Year.as_str(), "year");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Year => "year",
+			Self::Month => "month",
+			Self::Day => "day",
+			Self::Hour => "hour",
+			Self::Minute => "minute",
+			Self::Second => "second",
+		}
+	}
+}
@@ -0,0 +1,67 @@
+/*!
+# UTC2K - Period
+*/
+
+use crate::macros;
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// # Period.
+///
+/// This is a simple enum representing the ante/post meridiem halves of a
+/// 12-hour clock.
+///
+/// It is returned by [`Utc2k::hour12_parts`](crate::Utc2k::hour12_parts).
+pub enum Period {
+	/// # AM.
+	Am,
+
+	/// # PM.
+	Pm,
+}
+
+macros::as_ref_borrow_cast!(Period: as_str str);
+macros::display_str!(as_str Period);
+
+impl Period {
+	#[must_use]
+	/// # As Str.
+	///
+	/// Return the period as an (uppercase) string slice.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::Period;
+	///
+	/// assert_eq!(Period::Am.as_str(), "AM");
+	/// assert_eq!(Period::Pm.as_str(), "PM");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Am => "AM",
+			Self::Pm => "PM",
+		}
+	}
+
+	#[must_use]
+	/// # From Hour.
+	///
+	/// Return the period for a 24-hour value (`0..=23`).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::Period;
+	///
+	/// assert_eq!(Period::from_hour(0), Period::Am);
+	/// assert_eq!(Period::from_hour(11), Period::Am);
+	/// assert_eq!(Period::from_hour(12), Period::Pm);
+	/// assert_eq!(Period::from_hour(23), Period::Pm);
+	/// ```
+	pub const fn from_hour(hh: u8) -> Self {
+		if hh < 12 { Self::Am }
+		else { Self::Pm }
+	}
+}
@@ -0,0 +1,554 @@
+/*!
+# UTC2K - Ranges
+*/
+
+use crate::{
+	DAY_IN_SECONDS,
+	Utc2k,
+};
+use std::{
+	cmp::Ordering,
+	iter::FusedIterator,
+};
+
+
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+/// # Date/Time Range.
+///
+/// This is a half-open range of [`Utc2k`] dates — `start..end` — as returned
+/// by [`Utc2k::range_to`], or an inclusive one — `start..=end` — as returned
+/// by [`Utc2k::iter_to`].
+///
+/// It can be used to test whether a given date falls within the range, or to
+/// iterate over the dates within it (forward or backward) at a fixed
+/// interval, one day by default.
+///
+/// ## Examples
+///
+/// ```
+/// use utc2k::Utc2k;
+///
+/// let start = Utc2k::new(2024, 1, 1, 0, 0, 0);
+/// let end = Utc2k::new(2024, 1, 4, 0, 0, 0);
+/// let range = start.range_to(end);
+///
+/// assert!(range.contains(start));
+/// assert!(! range.contains(end));
+///
+/// assert_eq!(
+///     range.map(|d| d.to_string()).collect::<Vec<_>>(),
+///     [
+///         "2024-01-01 00:00:00",
+///         "2024-01-02 00:00:00",
+///         "2024-01-03 00:00:00",
+///     ],
+/// );
+///
+/// // Backwards, by the hour.
+/// let mut range = start.range_to(end).step_secs(3600);
+/// assert_eq!(range.len(), 72);
+/// assert_eq!(range.next_back(), Some(Utc2k::new(2024, 1, 3, 23, 0, 0)));
+/// ```
+pub struct Utc2kRange {
+	/// # Start (Inclusive).
+	start: Utc2k,
+
+	/// # End.
+	end: Utc2k,
+
+	/// # Inclusive End?
+	inclusive: bool,
+
+	/// # Step (Seconds).
+	step: u32,
+
+	/// # Front Offset (Steps From Start).
+	front: u32,
+
+	/// # Back Offset (Steps From Start).
+	back: u32,
+}
+
+impl Iterator for Utc2kRange {
+	type Item = Utc2k;
+
+	/// # Next Date.
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.front < self.back {
+			let next = self.nth_date(self.front);
+			self.front += 1;
+			Some(next)
+		}
+		else { None }
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl DoubleEndedIterator for Utc2kRange {
+	/// # Previous Date.
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.front < self.back {
+			self.back -= 1;
+			Some(self.nth_date(self.back))
+		}
+		else { None }
+	}
+}
+
+impl ExactSizeIterator for Utc2kRange {
+	#[inline]
+	fn len(&self) -> usize { (self.back - self.front) as usize }
+}
+
+impl FusedIterator for Utc2kRange {}
+
+impl Utc2kRange {
+	#[must_use]
+	/// # New.
+	///
+	/// If `end` comes before `start`, it will be moved up to `start`,
+	/// resulting in an empty range.
+	pub(crate) const fn new(start: Utc2k, end: Utc2k) -> Self {
+		let end = if start.const_cmp(end).is_gt() { start } else { end };
+		Self { start, end, inclusive: false, step: DAY_IN_SECONDS, front: 0, back: 0 }.reset()
+	}
+
+	#[must_use]
+	/// # New (Inclusive).
+	///
+	/// Same as [`Utc2kRange::new`], but `end` is included (if a step lands
+	/// on it).
+	///
+	/// If `end` comes before `start`, the result is an empty half-open range.
+	pub(crate) const fn new_inclusive(start: Utc2k, end: Utc2k, step: u32) -> Self {
+		if start.const_cmp(end).is_gt() { Self::new(start, start).step_secs(step) }
+		else {
+			Self { start, end, inclusive: true, step: DAY_IN_SECONDS, front: 0, back: 0 }
+				.step_secs(step)
+		}
+	}
+
+	#[must_use]
+	/// # Step (Seconds).
+	///
+	/// Change the iteration interval, in seconds. (The default is one day.)
+	///
+	/// Zero is treated as one.
+	///
+	/// Note: this resets any iteration progress.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::Utc2k;
+	///
+	/// let start = Utc2k::new(2024, 1, 1, 0, 0, 0);
+	/// let range = start.range_to(start + 60_u32).step_secs(15);
+	/// assert_eq!(
+	///     range.map(|d| d.second()).collect::<Vec<_>>(),
+	///     [0, 15, 30, 45],
+	/// );
+	/// ```
+	pub const fn step_secs(mut self, step: u32) -> Self {
+		self.step = if step == 0 { 1 } else { step };
+		self.reset()
+	}
+
+	#[must_use]
+	/// # Start.
+	///
+	/// Return the (inclusive) start of the range.
+	pub const fn start(&self) -> Utc2k { self.start }
+
+	#[must_use]
+	/// # End.
+	///
+	/// Return the end of the range. See [`Utc2kRange::is_inclusive`] to
+	/// determine whether or not it is part of the range.
+	pub const fn end(&self) -> Utc2k { self.end }
+
+	#[must_use]
+	/// # Is Inclusive?
+	///
+	/// Returns `true` if the end is part of the range — `start..=end` — as
+	/// with [`Utc2k::iter_to`], or `false` if it is not — `start..end` — as
+	/// with [`Utc2k::range_to`].
+	pub const fn is_inclusive(&self) -> bool { self.inclusive }
+
+	#[must_use]
+	/// # Contains?
+	///
+	/// Returns `true` if the date falls within `start..end` (or
+	/// `start..=end`, if inclusive).
+	///
+	/// Note: this ignores the step.
+	pub const fn contains(&self, date: Utc2k) -> bool {
+		self.start.const_cmp(date).is_le() &&
+		match date.const_cmp(self.end) {
+			Ordering::Less => true,
+			Ordering::Equal => self.inclusive,
+			Ordering::Greater => false,
+		}
+	}
+
+	#[must_use]
+	/// # Is Empty?
+	///
+	/// Returns `true` if the range has no dates, i.e. `start..start`.
+	pub const fn is_empty(&self) -> bool {
+		! self.inclusive && self.start.const_eq(self.end)
+	}
+
+	/// # Nth Date.
+	fn nth_date(&self, n: u32) -> Utc2k {
+		Utc2k::from(self.start.unixtime() + n * self.step)
+	}
+
+	/// # Reset Offsets.
+	const fn reset(mut self) -> Self {
+		let diff = self.end.unixtime() - self.start.unixtime();
+		self.front = 0;
+		self.back =
+			if self.inclusive { diff / self.step + 1 }
+			else { diff.div_ceil(self.step) };
+		self
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Interval.
+///
+/// This is a lightweight half-open span of time — `start..end` — useful for
+/// comparing schedules, detecting conflicts, and the like, without having to
+/// remember which comparisons should be `<` and which `<=`.
+///
+/// Because the end is exclusive, back-to-back intervals (where one ends
+/// exactly when the next begins) do _not_ overlap.
+///
+/// ## Examples
+///
+/// ```
+/// use utc2k::{Interval, Utc2k};
+///
+/// let morning = Interval::new(
+///     Utc2k::new(2024, 1, 1, 9, 0, 0),
+///     Utc2k::new(2024, 1, 1, 12, 0, 0),
+/// );
+/// let lunch = Interval::new(
+///     Utc2k::new(2024, 1, 1, 12, 0, 0),
+///     Utc2k::new(2024, 1, 1, 13, 0, 0),
+/// );
+/// let meeting = Interval::new(
+///     Utc2k::new(2024, 1, 1, 11, 30, 0),
+///     Utc2k::new(2024, 1, 1, 12, 30, 0),
+/// );
+///
+/// assert!(! morning.overlaps(&lunch));
+/// assert!(morning.overlaps(&meeting));
+/// assert!(lunch.overlaps(&meeting));
+///
+/// assert_eq!(
+///     morning.intersection(&meeting),
+///     Some(Interval::new(
+///         Utc2k::new(2024, 1, 1, 11, 30, 0),
+///         Utc2k::new(2024, 1, 1, 12, 0, 0),
+///     )),
+/// );
+/// ```
+pub struct Interval {
+	/// # Start (Inclusive).
+	start: Utc2k,
+
+	/// # End (Exclusive).
+	end: Utc2k,
+}
+
+impl Interval {
+	#[must_use]
+	/// # New.
+	///
+	/// Create a new interval spanning `start` (inclusive) to `end`
+	/// (exclusive).
+	///
+	/// If `end` comes before `start`, the two will be swapped, so the result
+	/// is always well-formed.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::{Interval, Utc2k};
+	///
+	/// let a = Utc2k::new(2024, 1, 1, 0, 0, 0);
+	/// let b = Utc2k::new(2024, 1, 2, 0, 0, 0);
+	/// assert_eq!(Interval::new(a, b), Interval::new(b, a));
+	/// assert_eq!(Interval::new(b, a).start(), a);
+	/// ```
+	pub const fn new(start: Utc2k, end: Utc2k) -> Self {
+		if start.const_cmp(end).is_gt() { Self { start: end, end: start } }
+		else { Self { start, end } }
+	}
+
+	#[must_use]
+	/// # Start.
+	///
+	/// Return the (inclusive) start of the interval.
+	pub const fn start(&self) -> Utc2k { self.start }
+
+	#[must_use]
+	/// # End.
+	///
+	/// Return the (exclusive) end of the interval.
+	pub const fn end(&self) -> Utc2k { self.end }
+
+	#[must_use]
+	/// # Is Empty?
+	///
+	/// Returns `true` if the interval has no duration, i.e. `start == end`.
+	/// Empty intervals contain nothing and overlap nothing.
+	pub const fn is_empty(&self) -> bool { self.start.const_eq(self.end) }
+
+	#[must_use]
+	/// # Length (Seconds).
+	///
+	/// Return the duration of the interval in seconds.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::{Interval, Utc2k};
+	///
+	/// let a = Utc2k::new(2024, 1, 1, 0, 0, 0);
+	/// assert_eq!(Interval::new(a, a + 90_u32).len_secs(), 90);
+	/// ```
+	pub const fn len_secs(&self) -> u32 { self.end.unixtime() - self.start.unixtime() }
+
+	#[must_use]
+	/// # Contains?
+	///
+	/// Returns `true` if the date falls within `start..end`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::{Interval, Utc2k};
+	///
+	/// let a = Utc2k::new(2024, 1, 1, 0, 0, 0);
+	/// let b = Utc2k::new(2024, 1, 2, 0, 0, 0);
+	/// let span = Interval::new(a, b);
+	/// assert!(span.contains(a));
+	/// assert!(span.contains(b - 1_u32));
+	/// assert!(! span.contains(b));
+	/// ```
+	pub const fn contains(&self, date: Utc2k) -> bool {
+		self.start.const_cmp(date).is_le() && date.const_cmp(self.end).is_lt()
+	}
+
+	#[must_use]
+	/// # Overlaps?
+	///
+	/// Returns `true` if the two intervals share at least one second in
+	/// common.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::{Interval, Utc2k};
+	///
+	/// let a = Utc2k::new(2024, 1, 1, 0, 0, 0);
+	/// let b = Utc2k::new(2024, 1, 2, 0, 0, 0);
+	/// let c = Utc2k::new(2024, 1, 3, 0, 0, 0);
+	///
+	/// // Touching isn't overlapping.
+	/// assert!(! Interval::new(a, b).overlaps(&Interval::new(b, c)));
+	///
+	/// // But one second more is.
+	/// assert!(Interval::new(a, b + 1_u32).overlaps(&Interval::new(b, c)));
+	/// ```
+	pub const fn overlaps(&self, other: &Self) -> bool {
+		! self.is_empty() &&
+		! other.is_empty() &&
+		self.start.const_cmp(other.end).is_lt() &&
+		other.start.const_cmp(self.end).is_lt()
+	}
+
+	#[must_use]
+	/// # Intersection.
+	///
+	/// Return the interval shared by both, or `None` if they don't overlap.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::{Interval, Utc2k};
+	///
+	/// let a = Utc2k::new(2024, 1, 1, 0, 0, 0);
+	/// let b = Utc2k::new(2024, 1, 2, 0, 0, 0);
+	/// let c = Utc2k::new(2024, 1, 3, 0, 0, 0);
+	///
+	/// assert_eq!(
+	///     Interval::new(a, c).intersection(&Interval::new(b, c)),
+	///     Some(Interval::new(b, c)),
+	/// );
+	/// assert!(Interval::new(a, b).intersection(&Interval::new(b, c)).is_none());
+	/// ```
+	pub const fn intersection(&self, other: &Self) -> Option<Self> {
+		if self.overlaps(other) {
+			let start = if self.start.const_cmp(other.start).is_gt() { self.start } else { other.start };
+			let end = if self.end.const_cmp(other.end).is_lt() { self.end } else { other.end };
+			Some(Self { start, end })
+		}
+		else { None }
+	}
+
+	#[must_use]
+	/// # Range.
+	///
+	/// Return a [`Utc2kRange`] spanning the same dates, for iteration.
+	pub const fn range(&self) -> Utc2kRange { Utc2kRange::new(self.start, self.end) }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	/// # Ranges.
+	fn t_range() {
+		let start = Utc2k::new(2024, 2, 27, 12, 0, 0);
+		let end = Utc2k::new(2024, 3, 2, 0, 0, 0);
+
+		// Forward and backward should match.
+		let range = start.range_to(end);
+		assert_eq!(range.len(), 4);
+		let fwd: Vec<Utc2k> = range.clone().collect();
+		let mut rev: Vec<Utc2k> = range.clone().rev().collect();
+		rev.reverse();
+		assert_eq!(fwd, rev);
+		assert_eq!(
+			fwd,
+			[
+				Utc2k::new(2024, 2, 27, 12, 0, 0),
+				Utc2k::new(2024, 2, 28, 12, 0, 0),
+				Utc2k::new(2024, 2, 29, 12, 0, 0),
+				Utc2k::new(2024, 3, 1, 12, 0, 0),
+			],
+		);
+
+		// Everything yielded should be contained.
+		assert!(fwd.iter().all(|d| range.contains(*d)));
+		assert!(! range.contains(end));
+		assert!(! range.contains(start - 1_u32));
+
+		// Meet in the middle.
+		let mut range = start.range_to(end).step_secs(7);
+		let len = range.len();
+		let mut total = 0;
+		loop {
+			let a = range.next();
+			let b = range.next_back();
+			total += usize::from(a.is_some()) + usize::from(b.is_some());
+			if let (Some(a), Some(b)) = (a, b) { assert!(a < b); }
+			else { break; }
+		}
+		assert_eq!(total, len);
+		assert_eq!(range.next(), None);
+		assert_eq!(range.next_back(), None);
+
+		// Empty/backward ranges.
+		let range = end.range_to(start);
+		assert!(range.is_empty());
+		assert_eq!(range.len(), 0);
+		assert_eq!(range.end(), end);
+
+		// Full century, by the second, from the back.
+		let mut range = Utc2k::MIN.range_to(Utc2k::MAX).step_secs(0);
+		assert_eq!(range.len(), (Utc2k::MAX_UNIXTIME - Utc2k::MIN_UNIXTIME) as usize);
+		assert_eq!(range.next_back(), Some(Utc2k::MAX - 1_u32));
+	}
+
+	#[test]
+	/// # Inclusive Ranges.
+	fn t_iter() {
+		let start = Utc2k::new(2024, 2, 27, 12, 0, 0);
+		let end = Utc2k::new(2024, 3, 2, 12, 0, 0);
+
+		// Inclusive, forward and backward.
+		let iter = start.iter_to(end, DAY_IN_SECONDS);
+		assert_eq!(iter.len(), 5);
+		let fwd: Vec<Utc2k> = iter.clone().collect();
+		let mut rev: Vec<Utc2k> = iter.rev().collect();
+		rev.reverse();
+		assert_eq!(fwd, rev);
+		assert_eq!(fwd.first(), Some(&start));
+		assert_eq!(fwd.last(), Some(&end));
+
+		// Should match the half-open range plus the end.
+		let mut range: Vec<Utc2k> = start.range_to(end).collect();
+		range.push(end);
+		assert_eq!(fwd, range);
+
+		// The end should be contained.
+		let iter = start.iter_to(end, DAY_IN_SECONDS);
+		assert!(iter.is_inclusive());
+		assert!(iter.contains(end));
+		assert!(! iter.contains(end + 1_u32));
+		assert!(! start.range_to(end).contains(end));
+
+		// Changing the step should keep it inclusive.
+		assert_eq!(iter.step_secs(3600).next_back(), Some(end));
+
+		// Single.
+		assert_eq!(start.iter_to(start, 0).collect::<Vec<_>>(), [start]);
+		assert!(! start.iter_to(start, 0).is_empty());
+
+		// Empty.
+		assert_eq!(end.iter_to(start, 1).len(), 0);
+		assert_eq!(end.iter_to(start, 1).next(), None);
+		assert!(end.iter_to(start, 1).is_empty());
+
+		// Full century, by the second.
+		let mut iter = Utc2k::MIN.iter_to(Utc2k::MAX, 1);
+		assert_eq!(iter.len(), (Utc2k::MAX_UNIXTIME - Utc2k::MIN_UNIXTIME + 1) as usize);
+		assert_eq!(iter.next_back(), Some(Utc2k::MAX));
+		assert_eq!(iter.next(), Some(Utc2k::MIN));
+	}
+
+	#[test]
+	/// # Intervals.
+	fn t_interval() {
+		let mut rng = fastrand::Rng::new();
+		let mut rand = || Utc2k::from(rng.u32(Utc2k::MIN_UNIXTIME..Utc2k::MIN_UNIXTIME + 1000));
+		for _ in 0..10_000 {
+			let a = Interval::new(rand(), rand());
+			let b = Interval::new(rand(), rand());
+			assert!(a.start() <= a.end());
+
+			// Brute-force the overlap.
+			let shared: Vec<Utc2k> = a.range().step_secs(1)
+				.filter(|d| b.contains(*d))
+				.collect();
+			assert_eq!(a.overlaps(&b), ! shared.is_empty(), "{a:?} {b:?}");
+			assert_eq!(a.overlaps(&b), b.overlaps(&a));
+
+			match a.intersection(&b) {
+				Some(i) => {
+					assert_eq!(i, b.intersection(&a).expect("Intersection should be symmetrical."));
+					assert_eq!(i.start(), shared[0]);
+					assert_eq!(i.end() - 1_u32, shared[shared.len() - 1]);
+					assert_eq!(i.len_secs() as usize, shared.len());
+				},
+				None => assert!(shared.is_empty()),
+			}
+		}
+	}
+}
@@ -0,0 +1,113 @@
+/*!
+# UTC2K - Season
+*/
+
+use crate::macros;
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Season.
+///
+/// This is a simple enum representing the four (meteorological) seasons.
+///
+/// It is returned by [`Utc2k::season_northern`](crate::Utc2k::season_northern)
+/// and [`Utc2k::season_southern`](crate::Utc2k::season_southern).
+pub enum Season {
+	/// # Spring.
+	Spring,
+
+	/// # Summer.
+	Summer,
+
+	/// # Autumn.
+	Autumn,
+
+	/// # Winter.
+	Winter,
+}
+
+macros::as_ref_borrow_cast!(Season: as_str str);
+macros::display_str!(as_str Season);
+
+impl Season {
+	#[must_use]
+	/// # As Str.
+	///
+	/// Return the season as a string slice.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::Season;
+	///
+	/// assert_eq!(Season::Autumn.as_str(), "Autumn");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Spring => "Spring",
+			Self::Summer => "Summer",
+			Self::Autumn => "Autumn",
+			Self::Winter => "Winter",
+		}
+	}
+
+	#[must_use]
+	/// # Opposite.
+	///
+	/// Return the season on the other side of the equator.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::Season;
+	///
+	/// assert_eq!(Season::Summer.opposite(), Season::Winter);
+	/// assert_eq!(Season::Spring.opposite(), Season::Autumn);
+	/// ```
+	pub const fn opposite(self) -> Self {
+		match self {
+			Self::Spring => Self::Autumn,
+			Self::Summer => Self::Winter,
+			Self::Autumn => Self::Spring,
+			Self::Winter => Self::Summer,
+		}
+	}
+
+	/// # From Month (Northern).
+	///
+	/// Return the meteorological season for the given month (`1..=12`) in
+	/// the northern hemisphere.
+	pub(crate) const fn from_month_northern(m: u8) -> Self {
+		match m {
+			3..=5 => Self::Spring,
+			6..=8 => Self::Summer,
+			9..=11 => Self::Autumn,
+			_ => Self::Winter,
+		}
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	/// # Test Months.
+	fn t_months() {
+		let expected = [
+			Season::Winter, Season::Winter,
+			Season::Spring, Season::Spring, Season::Spring,
+			Season::Summer, Season::Summer, Season::Summer,
+			Season::Autumn, Season::Autumn, Season::Autumn,
+			Season::Winter,
+		];
+		for (m, s) in (1..=12_u8).zip(expected) {
+			assert_eq!(Season::from_month_northern(m), s);
+			assert_eq!(Season::from_month_northern(m).opposite().opposite(), s);
+			assert_eq!(Season::from_month_northern(m).to_string(), s.as_str());
+		}
+	}
+}
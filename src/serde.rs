@@ -1,12 +1,22 @@
 /*!
 # (De/)Serialization
+
+[`Utc2k`] and [`FmtUtc2k`] implement [`Serialize`] and [`Deserialize`]
+directly when the `serde` crate feature is enabled. Deserialization is lenient by default, saturating out-of-range
+timestamps to the nearest boundary.
+
+For fields that should reject such values instead, see the [`strict`] module;
+for millisecond timestamps, see [`unix_millis`]; for RFC2822 strings, see
+[`rfc2822`].
 */
 
 use crate::{
 	FmtUtc2k,
 	Month,
+	MonthSet,
 	Utc2k,
 	Weekday,
+	WeekdaySet,
 };
 use serde::{
 	de,
@@ -46,6 +56,9 @@ impl<'de> Deserialize<'de> for Utc2k {
 	/// # Deserialize.
 	///
 	/// Use the optional `serde` crate feature to enable serialization support.
+	///
+	/// Unix timestamps and `YYYY-MM-DD HH:MM:SS`-style strings are accepted,
+	/// as are RFC2822 strings with a leading weekday.
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where D: de::Deserializer<'de> {
 		/// # Visitor Instance.
@@ -70,12 +83,25 @@ impl<'de> Deserialize<'de> for Utc2k {
 
 			fn visit_str<S>(self, src: &str) -> Result<Self::Value, S>
 			where S: de::Error {
-				Utc2k::try_from(src).map_err(|_| de::Error::custom("invalid datetime string"))
+				// Strings starting with a weekday are probably RFC2822.
+				if src.starts_with(|c: char| c.is_ascii_alphabetic()) {
+					Utc2k::from_rfc2822(src).ok_or_else(|| de::Error::custom("invalid datetime string"))
+				}
+				else {
+					Utc2k::try_from(src).map_err(|_| de::Error::custom("invalid datetime string"))
+				}
 			}
 
 			fn visit_bytes<S>(self, src: &[u8]) -> Result<Self::Value, S>
 			where S: de::Error {
-				Utc2k::try_from(src).map_err(|_| de::Error::custom("invalid datetime string"))
+				if src.first().is_some_and(u8::is_ascii_alphabetic) {
+					std::str::from_utf8(src).ok()
+						.and_then(Utc2k::from_rfc2822)
+						.ok_or_else(|| de::Error::custom("invalid datetime string"))
+				}
+				else {
+					Utc2k::try_from(src).map_err(|_| de::Error::custom("invalid datetime string"))
+				}
 			}
 
 			fn visit_i32<S>(self, src: i32) -> Result<Self::Value, S>
@@ -104,6 +130,15 @@ impl<'de> Deserialize<'de> for Utc2k {
 				Ok(u32::try_from(src).map_or_else(|_| Utc2k::MAX, Utc2k::from))
 			}
 
+			fn visit_f64<S>(self, src: f64) -> Result<Self::Value, S>
+			where S: de::Error {
+				// Fail on negative or NaN, otherwise floor and saturate.
+				if src.is_nan() || src.is_sign_negative() {
+					Err(de::Error::custom("invalid unix timestamp"))
+				}
+				else { Ok(Utc2k::from_secs_f64_lossy(src)) }
+			}
+
 			// Too small to hold an in-range value.
 			invalid!(visit_char, char);
 			invalid!(visit_i8, i8);
@@ -222,6 +257,420 @@ impl Serialize for Weekday {
 
 
 
+
+
+/// # Helper: Set (De/)Serialization.
+macro_rules! set {
+	($set:ident $enum:ident $int:ty, $ser:ident $de:ident $visit:ident) => (
+		#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+		impl<'de> Deserialize<'de> for $set {
+			/// # Deserialize.
+			///
+			/// Use the optional `serde` crate feature to enable serialization support.
+			///
+			/// Human-readable formats may use either an array of names or
+			/// the raw bitmask; others use the bitmask.
+			fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+			where D: de::Deserializer<'de> {
+				/// # Visitor Instance.
+				struct Visitor;
+
+				impl<'de> de::Visitor<'de> for Visitor {
+					type Value = $set;
+
+					fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+						f.write_str(concat!(
+							"a list of ", stringify!($enum), " names or a bitmask",
+						))
+					}
+
+					fn $visit<S>(self, src: $int) -> Result<Self::Value, S>
+					where S: de::Error { Ok($set::from_bits(src)) }
+
+					fn visit_u64<S>(self, src: u64) -> Result<Self::Value, S>
+					where S: de::Error {
+						<$int>::try_from(src)
+							.map($set::from_bits)
+							.map_err(|_| de::Error::custom(concat!("invalid ", stringify!($set), " bitmask")))
+					}
+
+					fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+					where A: de::SeqAccess<'de> {
+						let mut out = $set::EMPTY;
+						while let Some(v) = seq.next_element::<$enum>()? { out.insert(v); }
+						Ok(out)
+					}
+				}
+
+				if deserializer.is_human_readable() { deserializer.deserialize_any(Visitor) }
+				else { deserializer.$de(Visitor) }
+			}
+		}
+
+		#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+		impl Serialize for $set {
+			/// # Serialize.
+			///
+			/// Use the optional `serde` crate feature to enable serialization support.
+			///
+			/// Human-readable formats get an array of names; others get the
+			/// raw bitmask.
+			fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+			where S: ser::Serializer {
+				if serializer.is_human_readable() {
+					serializer.collect_seq(self.iter().map($enum::as_str))
+				}
+				else { serializer.$ser(self.bits()) }
+			}
+		}
+	);
+}
+
+set!(WeekdaySet Weekday u8, serialize_u8 deserialize_u8 visit_u8);
+set!(MonthSet Month u16, serialize_u16 deserialize_u16 visit_u16);
+
+
+/// # Strict (De/)Serialization.
+///
+/// This module can be used with serde's `with` field attribute —
+/// `#[serde(with = "utc2k::serde::strict")]` — to deserialize [`Utc2k`] or
+/// [`FmtUtc2k`] values _strictly_, returning an error rather than saturating
+/// when a timestamp or date/time string falls outside the supported
+/// `2000..=2099` range.
+///
+/// Serialization is unchanged.
+///
+/// ## Examples
+///
+/// ```
+/// use utc2k::{FmtUtc2k, Utc2k};
+///
+/// // Helper to mimic what serde's derive macro would do.
+/// fn strict<T: From<Utc2k>>(raw: &str) -> Result<T, serde_json::Error> {
+///     let mut de = serde_json::Deserializer::from_str(raw);
+///     utc2k::serde::strict::deserialize(&mut de)
+/// }
+///
+/// // In-range values are fine.
+/// let a: Utc2k = strict("1625743996").unwrap();
+/// let b: FmtUtc2k = strict("\"2021-07-08 11:33:16\"").unwrap();
+/// assert_eq!(a, b);
+///
+/// // Out-of-range ones are not.
+/// assert!(strict::<Utc2k>("0").is_err());
+/// assert!(strict::<FmtUtc2k>("\"2100-01-01 00:00:00\"").is_err());
+///
+/// // The default implementation would have saturated instead.
+/// assert_eq!(serde_json::from_str::<Utc2k>("0").unwrap(), Utc2k::MIN);
+/// ```
+pub mod strict {
+	use crate::Utc2k;
+	#[cfg(doc)] use crate::FmtUtc2k;
+	use serde::{
+		de,
+		Serialize,
+		ser,
+	};
+	use std::fmt;
+
+	/// # Deserialize (Strictly).
+	///
+	/// Deserialize a [`Utc2k`] or [`FmtUtc2k`] from a unix timestamp or
+	/// date/time string, returning an error if the value is negative, out of
+	/// range, or otherwise invalid.
+	///
+	/// ## Errors
+	///
+	/// The error message will include the offending value.
+	pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+	where D: de::Deserializer<'de>, T: From<Utc2k> {
+		/// # Visitor Instance.
+		struct Visitor;
+
+		/// # Helper: Range-Checked Timestamps.
+		macro_rules! unixtime {
+			($($fn:ident $ty:ty),+ $(,)?) => ($(
+				fn $fn<S>(self, src: $ty) -> Result<Self::Value, S>
+				where S: de::Error { unixtime(src) }
+			)+);
+		}
+
+		/// # Helper: Range-Checked Timestamp.
+		fn unixtime<S, N>(src: N) -> Result<Utc2k, S>
+		where S: de::Error, N: Copy + fmt::Display + TryInto<u32> {
+			src.try_into()
+				.ok()
+				.filter(|n| (Utc2k::MIN_UNIXTIME..=Utc2k::MAX_UNIXTIME).contains(n))
+				.map(Utc2k::from)
+				.ok_or_else(|| de::Error::custom(format_args!(
+					"unix timestamp {src} is out of range ({}..={})",
+					Utc2k::MIN_UNIXTIME,
+					Utc2k::MAX_UNIXTIME,
+				)))
+		}
+
+		impl de::Visitor<'_> for Visitor {
+			type Value = Utc2k;
+
+			fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				f.write_str("an in-range timestamp or datetime string")
+			}
+
+			fn visit_str<S>(self, src: &str) -> Result<Self::Value, S>
+			where S: de::Error {
+				Utc2k::checked_from_ascii_strict(src).map_err(|e| de::Error::custom(
+					format_args!("invalid datetime string {src:?}: {e}")
+				))
+			}
+
+			fn visit_bytes<S>(self, src: &[u8]) -> Result<Self::Value, S>
+			where S: de::Error {
+				Utc2k::checked_from_ascii_strict(src).map_err(|e| de::Error::custom(
+					format_args!("invalid datetime string {:?}: {e}", String::from_utf8_lossy(src))
+				))
+			}
+
+			fn visit_char<S>(self, src: char) -> Result<Self::Value, S>
+			where S: de::Error {
+				Err(de::Error::custom(format_args!("char {src:?} is unsupported")))
+			}
+
+			// Small integers can't hold an in-range value, but are run
+			// through the same check so the error names the offending value.
+			unixtime!(
+				visit_i8 i8, visit_i16 i16, visit_i32 i32, visit_i64 i64,
+				visit_u8 u8, visit_u16 u16, visit_u32 u32, visit_u64 u64,
+			);
+
+			fn visit_f64<S>(self, src: f64) -> Result<Self::Value, S>
+			where S: de::Error {
+				Utc2k::try_from_secs_f64(src).map_err(|_| de::Error::custom(format_args!(
+					"unix timestamp {src} is out of range ({}..={})",
+					Utc2k::MIN_UNIXTIME,
+					Utc2k::MAX_UNIXTIME,
+				)))
+			}
+		}
+
+		deserializer.deserialize_any(Visitor).map(T::from)
+	}
+
+	#[inline]
+	/// # Serialize.
+	///
+	/// This is identical to the default [`Serialize`] implementations, and
+	/// exists only so the module can be used with `#[serde(with = "…")]`.
+	///
+	/// ## Errors
+	///
+	/// Serialization errors are passed through as-is.
+	pub fn serialize<T, S>(src: &T, serializer: S) -> Result<S::Ok, S::Error>
+	where T: Serialize, S: ser::Serializer { src.serialize(serializer) }
+}
+
+
+
+
+/// # Unix Millisecond (De/)Serialization.
+///
+/// This module can be used with serde's `with` field attribute —
+/// `#[serde(with = "utc2k::serde::unix_millis")]` — to (de)serialize
+/// [`Utc2k`] or [`FmtUtc2k`] values as millisecond-precision Unix timestamps,
+/// like those produced by JavaScript's `Date.now()`.
+///
+/// Deserialization floors fractional seconds and saturates out-of-range
+/// values, same as [`Utc2k::from_unix_millis`]. Date/time strings are also
+/// accepted, as with the default implementation.
+///
+/// ## Examples
+///
+/// ```
+/// use utc2k::{FmtUtc2k, Utc2k};
+///
+/// // Helpers to mimic what serde's derive macro would do.
+/// fn de<T: From<Utc2k>>(raw: &str) -> Result<T, serde_json::Error> {
+///     let mut de = serde_json::Deserializer::from_str(raw);
+///     utc2k::serde::unix_millis::deserialize(&mut de)
+/// }
+/// fn ser<T: Copy + Into<Utc2k>>(src: T) -> String {
+///     let mut out = Vec::new();
+///     let mut ser = serde_json::Serializer::new(&mut out);
+///     utc2k::serde::unix_millis::serialize(&src, &mut ser).unwrap();
+///     String::from_utf8(out).unwrap()
+/// }
+///
+/// let date = Utc2k::new(2020, 10, 15, 20, 25, 30);
+/// assert_eq!(ser(date), "1602793530000");
+/// assert_eq!(ser(FmtUtc2k::from(date)), "1602793530000");
+///
+/// assert_eq!(de::<Utc2k>("1602793530999").unwrap(), date);
+/// assert_eq!(de::<FmtUtc2k>("1602793530999").unwrap(), date);
+/// ```
+pub mod unix_millis {
+	use crate::Utc2k;
+	#[cfg(doc)] use crate::FmtUtc2k;
+	use serde::{
+		de,
+		ser,
+	};
+	use std::fmt;
+
+	/// # Deserialize.
+	///
+	/// Deserialize a [`Utc2k`] or [`FmtUtc2k`] from a millisecond Unix
+	/// timestamp or date/time string.
+	///
+	/// ## Errors
+	///
+	/// An error is returned for negative timestamps or invalid strings.
+	pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+	where D: de::Deserializer<'de>, T: From<Utc2k> {
+		/// # Visitor Instance.
+		struct Visitor;
+
+		impl de::Visitor<'_> for Visitor {
+			type Value = Utc2k;
+
+			fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				f.write_str("a millisecond timestamp or datetime string")
+			}
+
+			fn visit_str<S>(self, src: &str) -> Result<Self::Value, S>
+			where S: de::Error {
+				Utc2k::try_from(src).map_err(|_| de::Error::custom("invalid datetime string"))
+			}
+
+			fn visit_bytes<S>(self, src: &[u8]) -> Result<Self::Value, S>
+			where S: de::Error {
+				Utc2k::try_from(src).map_err(|_| de::Error::custom("invalid datetime string"))
+			}
+
+			fn visit_i64<S>(self, src: i64) -> Result<Self::Value, S>
+			where S: de::Error {
+				// Fail on negative, otherwise parse as usual.
+				u64::try_from(src)
+					.map(Utc2k::from_unix_millis)
+					.map_err(|_| de::Error::custom("invalid unix timestamp"))
+			}
+
+			fn visit_u64<S>(self, src: u64) -> Result<Self::Value, S>
+			where S: de::Error { Ok(Utc2k::from_unix_millis(src)) }
+		}
+
+		deserializer.deserialize_any(Visitor).map(T::from)
+	}
+
+	/// # Serialize.
+	///
+	/// Serialize a [`Utc2k`] or [`FmtUtc2k`] as a millisecond Unix
+	/// timestamp.
+	///
+	/// ## Errors
+	///
+	/// Serialization errors are passed through as-is.
+	pub fn serialize<T, S>(src: &T, serializer: S) -> Result<S::Ok, S::Error>
+	where T: Copy + Into<Utc2k>, S: ser::Serializer {
+		serializer.serialize_u64((*src).into().to_unix_millis())
+	}
+}
+
+
+
+/// # RFC2822 (De/)Serialization.
+///
+/// This module can be used with serde's `with` field attribute —
+/// `#[serde(with = "utc2k::serde::rfc2822")]` — to (de)serialize [`Utc2k`]
+/// or [`FmtUtc2k`] values as RFC2822 strings, like those found in email and
+/// HTTP headers.
+///
+/// Deserialization works exactly like [`Utc2k::from_rfc2822`]; the weekday
+/// and offset are optional, and the latter is applied if present.
+///
+/// ## Examples
+///
+/// ```
+/// use utc2k::{FmtUtc2k, Utc2k};
+///
+/// // Helpers to mimic what serde's derive macro would do.
+/// fn de<T: From<Utc2k>>(raw: &str) -> Result<T, serde_json::Error> {
+///     let mut de = serde_json::Deserializer::from_str(raw);
+///     utc2k::serde::rfc2822::deserialize(&mut de)
+/// }
+/// fn ser<T: Copy + Into<Utc2k>>(src: T) -> String {
+///     let mut out = Vec::new();
+///     let mut ser = serde_json::Serializer::new(&mut out);
+///     utc2k::serde::rfc2822::serialize(&src, &mut ser).unwrap();
+///     String::from_utf8(out).unwrap()
+/// }
+///
+/// let date = Utc2k::new(2003, 7, 1, 10, 52, 37);
+/// assert_eq!(ser(date), "\"Tue, 01 Jul 2003 10:52:37 +0000\"");
+/// assert_eq!(ser(FmtUtc2k::from(date)), "\"Tue, 01 Jul 2003 10:52:37 +0000\"");
+///
+/// assert_eq!(de::<Utc2k>("\"Tue, 01 Jul 2003 10:52:37 +0000\"").unwrap(), date);
+/// assert_eq!(de::<FmtUtc2k>("\"1 Jul 2003 12:52:37 +0200\"").unwrap(), date);
+/// assert!(de::<Utc2k>("\"2003-07-01 10:52:37\"").is_err());
+/// ```
+pub mod rfc2822 {
+	use crate::Utc2k;
+	#[cfg(doc)] use crate::FmtUtc2k;
+	use serde::{
+		de,
+		ser,
+	};
+	use std::fmt;
+
+	/// # Deserialize.
+	///
+	/// Deserialize a [`Utc2k`] or [`FmtUtc2k`] from an RFC2822 date/time
+	/// string.
+	///
+	/// ## Errors
+	///
+	/// An error is returned if the value is not a valid RFC2822 string.
+	pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+	where D: de::Deserializer<'de>, T: From<Utc2k> {
+		/// # Visitor Instance.
+		struct Visitor;
+
+		impl de::Visitor<'_> for Visitor {
+			type Value = Utc2k;
+
+			fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				f.write_str("an RFC2822 datetime string")
+			}
+
+			fn visit_str<S>(self, src: &str) -> Result<Self::Value, S>
+			where S: de::Error {
+				Utc2k::from_rfc2822(src).ok_or_else(|| de::Error::custom("invalid RFC2822 string"))
+			}
+
+			fn visit_bytes<S>(self, src: &[u8]) -> Result<Self::Value, S>
+			where S: de::Error {
+				std::str::from_utf8(src).ok()
+					.and_then(Utc2k::from_rfc2822)
+					.ok_or_else(|| de::Error::custom("invalid RFC2822 string"))
+			}
+		}
+
+		deserializer.deserialize_str(Visitor).map(T::from)
+	}
+
+	/// # Serialize.
+	///
+	/// Serialize a [`Utc2k`] or [`FmtUtc2k`] as an RFC2822 string, using the
+	/// same format as [`Utc2k::to_rfc2822`].
+	///
+	/// ## Errors
+	///
+	/// Serialization errors are passed through as-is.
+	pub fn serialize<T, S>(src: &T, serializer: S) -> Result<S::Ok, S::Error>
+	where T: Copy + Into<Utc2k>, S: ser::Serializer {
+		serializer.collect_str(&(*src).into().rfc2822())
+	}
+}
+
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -274,6 +723,27 @@ mod tests {
 		}
 	}
 
+	#[test]
+	/// # Test Float Deserialization.
+	fn t_serde_f64() {
+		/// # Helper: Strict Deserialize.
+		fn strict(raw: &str) -> Result<Utc2k, String> {
+			super::strict::deserialize(&mut serde_json::Deserializer::from_str(raw))
+				.map_err(|e| e.to_string())
+		}
+
+		let date = Utc2k::from(1_750_620_170_u32);
+		assert_eq!(serde_json::from_str::<Utc2k>("1750620170.53").unwrap(), date);
+		assert_eq!(serde_json::from_str::<FmtUtc2k>("1750620170.53").unwrap(), date);
+		assert_eq!(serde_json::from_str::<Utc2k>("1.0").unwrap(), Utc2k::MIN);
+		assert_eq!(serde_json::from_str::<Utc2k>("1e300").unwrap(), Utc2k::MAX);
+		assert!(serde_json::from_str::<Utc2k>("-1.5").is_err());
+
+		assert_eq!(strict("1750620170.53"), Ok(date));
+		assert!(strict("1.5").is_err_and(|e| e.contains("1.5")));
+		assert!(strict("-1.5").is_err_and(|e| e.contains("-1.5")));
+	}
+
 	#[test]
 	fn t_serde_fmtutc2k_rng() {
 		let mut rng = fastrand::Rng::new();
@@ -307,6 +777,153 @@ mod tests {
 		}
 	}
 
+	#[test]
+	/// # Test Strict Deserialization.
+	fn t_serde_strict() {
+		/// # Helper: Parse One Value Both Ways.
+		fn parse(raw: &str) -> Result<(Utc2k, FmtUtc2k), String> {
+			let a: Utc2k = super::strict::deserialize(&mut serde_json::Deserializer::from_str(raw))
+				.map_err(|e| e.to_string())?;
+			let b: FmtUtc2k = super::strict::deserialize(&mut serde_json::Deserializer::from_str(raw))
+				.map_err(|e| e.to_string())?;
+			Ok((a, b))
+		}
+
+		// The boundaries should work.
+		for (raw, expected) in [
+			(Utc2k::MIN_UNIXTIME.to_string(), Utc2k::MIN),
+			(Utc2k::MAX_UNIXTIME.to_string(), Utc2k::MAX),
+			("\"2000-01-01 00:00:00\"".to_owned(), Utc2k::MIN),
+			("\"2099-12-31 23:59:59\"".to_owned(), Utc2k::MAX),
+		] {
+			let (a, b) = parse(&raw).expect("Strict deserialization failed.");
+			assert_eq!(a, expected);
+			assert_eq!(b, FmtUtc2k::from(expected));
+		}
+
+		// But anything outside them should not.
+		for raw in [
+			u32::MIN.to_string(),
+			(Utc2k::MIN_UNIXTIME - 1).to_string(),
+			(Utc2k::MAX_UNIXTIME + 1).to_string(),
+			u32::MAX.to_string(),
+			u64::MAX.to_string(),
+			(-1_i64).to_string(),
+			i64::MIN.to_string(),
+			"\"1999-12-31 23:59:59\"".to_owned(),
+			"\"2100-01-01 00:00:00\"".to_owned(),
+		] {
+			let err = parse(&raw).expect_err("Strict deserialization should have failed.");
+			assert!(
+				err.contains(raw.trim_matches('"')),
+				"Error does not mention {raw}: {err}",
+			);
+		}
+
+		// Types too small for any timestamp should mention the value too.
+		let err = super::strict::deserialize::<_, Utc2k>(
+			de::value::U8Deserializer::<de::value::Error>::new(123)
+		).expect_err("Strict deserialization should have failed.").to_string();
+		assert!(err.contains("123"), "Error does not mention 123: {err}");
+		let err = super::strict::deserialize::<_, Utc2k>(
+			de::value::I16Deserializer::<de::value::Error>::new(-321)
+		).expect_err("Strict deserialization should have failed.").to_string();
+		assert!(err.contains("-321"), "Error does not mention -321: {err}");
+		let err = super::strict::deserialize::<_, Utc2k>(
+			de::value::CharDeserializer::<de::value::Error>::new('x')
+		).expect_err("Strict deserialization should have failed.").to_string();
+		assert!(err.contains("'x'"), "Error does not mention 'x': {err}");
+
+		// The lenient default should still saturate.
+		assert_eq!(serde_json::from_str::<Utc2k>("0").unwrap(), Utc2k::MIN);
+		assert_eq!(serde_json::from_str::<Utc2k>(&u64::MAX.to_string()).unwrap(), Utc2k::MAX);
+	}
+
+	#[test]
+	/// # Test Millisecond (De/)Serialization.
+	fn t_serde_unix_millis() {
+		/// # Helper: Deserialize.
+		fn de(raw: &str) -> Result<Utc2k, serde_json::Error> {
+			super::unix_millis::deserialize(&mut serde_json::Deserializer::from_str(raw))
+		}
+
+		/// # Helper: Serialize.
+		fn ser(src: Utc2k) -> String {
+			let mut out = Vec::new();
+			super::unix_millis::serialize(&src, &mut serde_json::Serializer::new(&mut out))
+				.expect("Serialization failed.");
+			String::from_utf8(out).expect("Invalid UTF-8.")
+		}
+
+		// Round trips floor to the second.
+		let mut rng = fastrand::Rng::new();
+		for _ in 0..10_000 {
+			let ms = rng.u64(u64::from(Utc2k::MIN_UNIXTIME) * 1000..=u64::from(Utc2k::MAX_UNIXTIME) * 1000 + 999);
+			let date = de(&ms.to_string()).expect("Deserialization failed.");
+			assert_eq!(date, Utc2k::from_unix_millis(ms));
+			assert_eq!(ser(date), (ms - ms % 1000).to_string());
+		}
+
+		// Boundaries.
+		assert_eq!(ser(Utc2k::MIN), (u64::from(Utc2k::MIN_UNIXTIME) * 1000).to_string());
+		assert_eq!(ser(Utc2k::MAX), (u64::from(Utc2k::MAX_UNIXTIME) * 1000).to_string());
+		assert_eq!(de("0").unwrap(), Utc2k::MIN);
+		assert_eq!(de(&u64::MAX.to_string()).unwrap(), Utc2k::MAX);
+		assert!(de("-1").is_err());
+
+		// Strings work too.
+		assert_eq!(de("\"2020-10-15 20:25:30\"").unwrap(), Utc2k::new(2020, 10, 15, 20, 25, 30));
+	}
+
+	#[test]
+	/// # Test RFC2822 (De/)Serialization.
+	fn t_serde_rfc2822() {
+		/// # Helper: Deserialize.
+		fn de(raw: &str) -> Result<Utc2k, serde_json::Error> {
+			super::rfc2822::deserialize(&mut serde_json::Deserializer::from_str(raw))
+		}
+
+		/// # Helper: Serialize.
+		fn ser(src: Utc2k) -> String {
+			let mut out = Vec::new();
+			super::rfc2822::serialize(&src, &mut serde_json::Serializer::new(&mut out))
+				.expect("Serialization failed.");
+			String::from_utf8(out).expect("Invalid UTF-8.")
+		}
+
+		// Round trips.
+		let mut rng = fastrand::Rng::new();
+		for i in std::iter::repeat_with(|| rng.u32(Utc2k::MIN_UNIXTIME..=Utc2k::MAX_UNIXTIME)).take(10_000) {
+			let date = Utc2k::from(i);
+			let s = ser(date);
+			assert_eq!(s, format!("{:?}", date.to_rfc2822()));
+			assert_eq!(de(&s).expect("Deserialization failed."), date);
+
+			// The default implementation should accept it too.
+			assert_eq!(serde_json::from_str::<Utc2k>(&s).expect("Deserialization failed."), date);
+			assert_eq!(serde_json::from_str::<FmtUtc2k>(&s).expect("Deserialization failed."), date);
+		}
+
+		// Offsets and missing weekdays.
+		let date = Utc2k::new(2003, 7, 10, 17, 52, 37);
+		assert_eq!(de("\"Thu, 10 Jul 2003 10:52:37 -0700\"").unwrap(), date);
+		assert_eq!(de("\"10 Jul 2003 17:52:37\"").unwrap(), date);
+
+		// Other formats are not RFC2822.
+		assert!(de("\"2003-07-10 17:52:37\"").is_err());
+		assert!(de("\"Thu, 10 Jul\"").is_err());
+		assert!(de("1057859557").is_err());
+
+		// Nor is garbage, even if it starts with a letter.
+		assert!(serde_json::from_str::<Utc2k>("\"Thursday\"").is_err());
+
+		// The default implementation can handle a mix.
+		let all: Vec<Utc2k> = serde_json::from_str(
+			r#"[1057859557, "2003-07-10 17:52:37", "Thu, 10 Jul 2003 10:52:37 -0700", 1057859557.5]"#
+		).expect("Deserialization failed.");
+		assert_eq!(all, [date; 4]);
+	}
+
 	#[test]
 	fn t_serde_month() {
 		for month in Month::all() {
@@ -323,6 +940,23 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn t_serde_sets() {
+		let days = WeekdaySet::from(Weekday::Monday) | Weekday::Friday;
+		let s = serde_json::to_string(&days).expect("Serialization failed.");
+		assert_eq!(s, r#"["Monday","Friday"]"#);
+		assert_eq!(serde_json::from_str::<WeekdaySet>(&s).expect("Deserialization failed."), days);
+		assert_eq!(serde_json::from_str::<WeekdaySet>(r#"["fri","mon","fri"]"#).unwrap(), days);
+		assert_eq!(serde_json::from_str::<WeekdaySet>(&days.bits().to_string()).unwrap(), days);
+		assert_eq!(serde_json::from_str::<WeekdaySet>("[]").unwrap(), WeekdaySet::EMPTY);
+		assert!(serde_json::from_str::<WeekdaySet>(r#"["Funday"]"#).is_err());
+
+		let s = serde_json::to_string(&MonthSet::ALL).expect("Serialization failed.");
+		assert_eq!(serde_json::from_str::<MonthSet>(&s).expect("Deserialization failed."), MonthSet::ALL);
+		assert_eq!(serde_json::from_str::<MonthSet>("4095").unwrap(), MonthSet::ALL);
+		assert!(serde_json::from_str::<MonthSet>("65536").is_err());
+	}
+
 	#[test]
 	fn t_serde_weekday() {
 		for day in Weekday::all() {
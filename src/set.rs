@@ -0,0 +1,396 @@
+/*!
+# UTC2K - Weekday/Month Sets
+*/
+
+use crate::{
+	Month,
+	Utc2k,
+	Weekday,
+};
+use std::{
+	fmt,
+	iter::FusedIterator,
+	ops::{
+		BitAnd,
+		BitAndAssign,
+		BitOr,
+		BitOrAssign,
+	},
+};
+
+
+
+/// # Helper: Set Types.
+macro_rules! set {
+	(
+		$set:ident $iter:ident $enum:ident $int:ty, $len:literal,
+		$first:ident $second:ident $last:ident
+	) => (
+		#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+		#[doc = concat!("# ", stringify!($enum), " Set.")]
+		///
+		#[doc = concat!(
+			"A compact bitset of [`", stringify!($enum), "`]s, useful for ",
+			"cron-like schedule masks.",
+		)]
+		///
+		/// Sets can be combined with `|` (union) and `&` (intersection), and
+		/// iterated in order.
+		///
+		/// ## Examples
+		///
+		/// ```
+		#[doc = concat!("use utc2k::{", stringify!($enum), ", ", stringify!($set), "};")]
+		///
+		#[doc = concat!(
+			"let mut set: ", stringify!($set), " = [", stringify!($enum), "::",
+			stringify!($second), ", ", stringify!($enum), "::", stringify!($first),
+			"].into_iter().collect();",
+		)]
+		#[doc = concat!("assert!(set.contains(", stringify!($enum), "::", stringify!($first), "));")]
+		#[doc = concat!("assert!(! set.contains(", stringify!($enum), "::", stringify!($last), "));")]
+		///
+		#[doc = concat!("set.insert(", stringify!($enum), "::", stringify!($last), ");")]
+		/// assert_eq!(
+		///     set.iter().collect::<Vec<_>>(),
+		#[doc = concat!(
+			"    [", stringify!($enum), "::", stringify!($first), ", ",
+			stringify!($enum), "::", stringify!($second), ", ",
+			stringify!($enum), "::", stringify!($last), "],",
+		)]
+		/// );
+		/// ```
+		pub struct $set($int);
+
+		impl BitAnd for $set {
+			type Output = Self;
+
+			#[inline]
+			fn bitand(self, other: Self) -> Self { self.intersection(other) }
+		}
+
+		impl BitAndAssign for $set {
+			#[inline]
+			fn bitand_assign(&mut self, other: Self) { *self = *self & other; }
+		}
+
+		impl BitOr for $set {
+			type Output = Self;
+
+			#[inline]
+			fn bitor(self, other: Self) -> Self { self.union(other) }
+		}
+
+		impl BitOr<$enum> for $set {
+			type Output = Self;
+
+			#[inline]
+			fn bitor(mut self, other: $enum) -> Self {
+				self.insert(other);
+				self
+			}
+		}
+
+		impl BitOrAssign for $set {
+			#[inline]
+			fn bitor_assign(&mut self, other: Self) { *self = *self | other; }
+		}
+
+		impl BitOrAssign<$enum> for $set {
+			#[inline]
+			fn bitor_assign(&mut self, other: $enum) { self.insert(other); }
+		}
+
+		impl fmt::Debug for $set {
+			fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+				f.debug_set().entries(self.iter()).finish()
+			}
+		}
+
+		impl Extend<$enum> for $set {
+			fn extend<I: IntoIterator<Item=$enum>>(&mut self, iter: I) {
+				for v in iter { self.insert(v); }
+			}
+		}
+
+		impl From<$enum> for $set {
+			#[inline]
+			fn from(src: $enum) -> Self { Self::EMPTY | src }
+		}
+
+		impl From<$set> for $int {
+			#[inline]
+			fn from(src: $set) -> Self { src.0 }
+		}
+
+		impl FromIterator<$enum> for $set {
+			fn from_iter<I: IntoIterator<Item=$enum>>(iter: I) -> Self {
+				let mut out = Self::EMPTY;
+				out.extend(iter);
+				out
+			}
+		}
+
+		impl IntoIterator for $set {
+			type Item = $enum;
+			type IntoIter = $iter;
+
+			#[inline]
+			fn into_iter(self) -> Self::IntoIter { self.iter() }
+		}
+
+		impl $set {
+			#[doc = concat!("# All ", stringify!($enum), "s.")]
+			pub const ALL: Self = Self(<$int>::MAX >> (<$int>::BITS - $len));
+
+			/// # Empty.
+			pub const EMPTY: Self = Self(0);
+
+			#[inline]
+			/// # Bit For Value.
+			const fn bit(v: $enum) -> $int { 1 << (v as u8 - 1) }
+
+			#[must_use]
+			/// # From Bits.
+			///
+			#[doc = concat!(
+				"Create a set from a raw bitmask, where the least significant bit ",
+				"corresponds to [`", stringify!($enum), "::", stringify!($first),
+				"`]. Unused high bits are ignored.",
+			)]
+			pub const fn from_bits(bits: $int) -> Self { Self(bits & Self::ALL.0) }
+
+			#[must_use]
+			/// # Bits.
+			///
+			/// Return the raw bitmask, suitable for storage or
+			/// [`from_bits`](Self::from_bits).
+			pub const fn bits(self) -> $int { self.0 }
+		}
+
+		impl $set {
+			#[inline]
+			/// # Insert.
+			///
+			/// Add a value to the set, returning `true` if it was not already
+			/// present.
+			pub const fn insert(&mut self, v: $enum) -> bool {
+				let bit = Self::bit(v);
+				let new = 0 == self.0 & bit;
+				self.0 |= bit;
+				new
+			}
+
+			#[inline]
+			/// # Remove.
+			///
+			/// Remove a value from the set, returning `true` if it was
+			/// present.
+			pub const fn remove(&mut self, v: $enum) -> bool {
+				let bit = Self::bit(v);
+				let old = 0 != self.0 & bit;
+				self.0 &= ! bit;
+				old
+			}
+
+			#[inline]
+			#[must_use]
+			/// # Contains?
+			///
+			/// Returns `true` if the value is in the set.
+			pub const fn contains(self, v: $enum) -> bool { 0 != self.0 & Self::bit(v) }
+
+			#[inline]
+			#[must_use]
+			/// # Is Empty?
+			pub const fn is_empty(self) -> bool { self.0 == 0 }
+
+			#[inline]
+			#[must_use]
+			/// # Length.
+			///
+			/// Return the number of values in the set.
+			pub const fn len(self) -> usize { self.0.count_ones() as usize }
+
+			#[inline]
+			#[must_use]
+			/// # Union.
+			///
+			/// Return a set containing the values from either set.
+			pub const fn union(self, other: Self) -> Self { Self(self.0 | other.0) }
+
+			#[inline]
+			#[must_use]
+			/// # Intersection.
+			///
+			/// Return a set containing only the values present in both sets.
+			pub const fn intersection(self, other: Self) -> Self { Self(self.0 & other.0) }
+
+			#[inline]
+			#[must_use]
+			/// # Iterate.
+			///
+			/// Return an iterator over the values in the set, in order.
+			pub const fn iter(self) -> $iter { $iter(self.0) }
+		}
+
+
+
+		#[derive(Debug, Clone)]
+		#[doc = concat!("# ", stringify!($set), " Iterator.")]
+		///
+		#[doc = concat!(
+			"This iterator yields each [`", stringify!($enum), "`] in a [`",
+			stringify!($set), "`], in order.",
+		)]
+		pub struct $iter($int);
+
+		impl Iterator for $iter {
+			type Item = $enum;
+
+			fn next(&mut self) -> Option<Self::Item> {
+				if self.0 == 0 { None }
+				else {
+					#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+					let idx = self.0.trailing_zeros() as u8;
+					self.0 &= self.0 - 1;
+					$enum::try_from_u8(idx + 1)
+				}
+			}
+
+			#[inline]
+			fn size_hint(&self) -> (usize, Option<usize>) {
+				let len = self.len();
+				(len, Some(len))
+			}
+		}
+
+		impl DoubleEndedIterator for $iter {
+			fn next_back(&mut self) -> Option<Self::Item> {
+				if self.0 == 0 { None }
+				else {
+					#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+					let idx = (<$int>::BITS - 1 - self.0.leading_zeros()) as u8;
+					self.0 &= ! (1 << idx);
+					$enum::try_from_u8(idx + 1)
+				}
+			}
+		}
+
+		impl ExactSizeIterator for $iter {
+			#[inline]
+			fn len(&self) -> usize { self.0.count_ones() as usize }
+		}
+
+		impl FusedIterator for $iter {}
+	);
+}
+
+set!(WeekdaySet WeekdaySetIter Weekday u8, 7, Sunday Monday Saturday);
+set!(MonthSet MonthSetIter Month u16, 12, January February December);
+
+
+
+impl Utc2k {
+	#[must_use]
+	/// # Matches Schedule?
+	///
+	/// Returns `true` if the date's weekday is in `days` _and_ its month is
+	/// in `months`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::{Month, MonthSet, Utc2k, Weekday, WeekdaySet};
+	///
+	/// let weekends = WeekdaySet::from(Weekday::Saturday) | Weekday::Sunday;
+	/// let summer = MonthSet::from(Month::June) | Month::July | Month::August;
+	///
+	/// let date = Utc2k::new(2024, 7, 6, 12, 0, 0); // Saturday.
+	/// assert!(date.matches(weekends, summer));
+	/// assert!(date.matches(WeekdaySet::ALL, MonthSet::ALL));
+	/// assert!(! date.matches(weekends, MonthSet::from(Month::June)));
+	/// assert!(! date.matches(WeekdaySet::EMPTY, summer));
+	/// ```
+	pub const fn matches(&self, days: WeekdaySet, months: MonthSet) -> bool {
+		days.contains(self.weekday()) && months.contains(self.month_enum())
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_weekday_set() {
+		assert_eq!(WeekdaySet::ALL.len(), 7);
+		assert_eq!(WeekdaySet::ALL.iter().collect::<Vec<_>>(), Weekday::all());
+		assert_eq!(WeekdaySet::ALL.iter().rev().count(), 7);
+		assert_eq!(WeekdaySet::from_bits(u8::MAX), WeekdaySet::ALL);
+		assert!(WeekdaySet::EMPTY.is_empty());
+
+		let mut set = WeekdaySet::EMPTY;
+		for (i, day) in Weekday::all().into_iter().enumerate() {
+			assert!(set.insert(day));
+			assert!(! set.insert(day));
+			assert!(set.contains(day));
+			assert_eq!(set.len(), i + 1);
+			assert_eq!(set.bits(), u8::MAX >> (7 - i));
+			assert_eq!(WeekdaySet::from(day).bits(), 1 << i);
+		}
+		assert_eq!(set, WeekdaySet::ALL);
+
+		for day in Weekday::all() {
+			assert!(set.remove(day));
+			assert!(! set.remove(day));
+			assert!(! set.contains(day));
+		}
+		assert!(set.is_empty());
+
+		let a: WeekdaySet = [Weekday::Monday, Weekday::Tuesday].into_iter().collect();
+		let b: WeekdaySet = [Weekday::Tuesday, Weekday::Friday].into_iter().collect();
+		assert_eq!((a | b).iter().collect::<Vec<_>>(), [Weekday::Monday, Weekday::Tuesday, Weekday::Friday]);
+		assert_eq!((a & b).iter().collect::<Vec<_>>(), [Weekday::Tuesday]);
+		assert_eq!((a | b).iter().rev().collect::<Vec<_>>(), [Weekday::Friday, Weekday::Tuesday, Weekday::Monday]);
+	}
+
+	#[test]
+	fn t_month_set() {
+		assert_eq!(MonthSet::ALL.len(), 12);
+		assert_eq!(MonthSet::ALL.iter().collect::<Vec<_>>(), Month::all());
+		assert_eq!(MonthSet::ALL.iter().rev().count(), 12);
+		assert_eq!(MonthSet::from_bits(u16::MAX), MonthSet::ALL);
+		assert!(MonthSet::EMPTY.is_empty());
+
+		let mut set = MonthSet::EMPTY;
+		for (i, month) in Month::all().into_iter().enumerate() {
+			assert!(set.insert(month));
+			assert!(set.contains(month));
+			assert_eq!(set.len(), i + 1);
+			assert_eq!(MonthSet::from(month).bits(), 1 << i);
+		}
+		assert_eq!(set, MonthSet::ALL);
+
+		let mut iter = set.iter();
+		assert_eq!(iter.next(), Some(Month::January));
+		assert_eq!(iter.next_back(), Some(Month::December));
+		assert_eq!(iter.len(), 10);
+	}
+
+	#[test]
+	fn t_matches() {
+		let mut rng = fastrand::Rng::new();
+		for _ in 0..10_000 {
+			let date = Utc2k::from(rng.u32(Utc2k::MIN_UNIXTIME..=Utc2k::MAX_UNIXTIME));
+			let days = WeekdaySet::from_bits(rng.u8(..));
+			let months = MonthSet::from_bits(rng.u16(..));
+			assert_eq!(
+				date.matches(days, months),
+				days.contains(date.weekday()) && months.contains(date.month_enum()),
+			);
+			assert!(date.matches(date.weekday().into(), date.month_enum().into()));
+		}
+	}
+}
@@ -9,6 +9,7 @@ use crate::{
 };
 use std::{
 	cmp::Ordering,
+	iter::FusedIterator,
 	ops::{
 		Add,
 		AddAssign,
@@ -330,6 +331,78 @@ impl Weekday {
 		]
 	}
 
+	#[must_use]
+	/// # Range.
+	///
+	/// Return an iterator over the weekdays from `self` to `end`, inclusive,
+	/// wrapping around if `end` comes before `self`.
+	///
+	/// Unlike the endless [`Weekday::into_iter`], this iterator stops once it has
+	/// reached `end`. It can also be run in reverse, and knows its length.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::Weekday;
+	///
+	/// let range = Weekday::Monday.range(Weekday::Friday);
+	/// assert_eq!(range.len(), 5);
+	/// assert_eq!(
+	///     range.collect::<Vec<_>>(),
+	///     [Weekday::Monday, Weekday::Tuesday, Weekday::Wednesday, Weekday::Thursday, Weekday::Friday],
+	/// );
+	///
+	/// // A range can also be a single weekday.
+	/// assert_eq!(
+	///     Weekday::Monday.range(Weekday::Monday).collect::<Vec<_>>(),
+	///     [Weekday::Monday],
+	/// );
+	/// ```
+	pub const fn range(self, end: Self) -> RangedWeekdayIter {
+		let start = self as u8;
+		let end = end as u8;
+		RangedWeekdayIter {
+			start: self,
+			front: 0,
+			back:
+				if start <= end { end - start + 1 }
+				else { 8 - start + end },
+		}
+	}
+
+	#[must_use]
+	/// # Range (Non-Wrapping).
+	///
+	/// Return an iterator over the weekdays from `self` to `end`, inclusive.
+	///
+	/// Unlike [`Weekday::range`], this never wraps around; if `end` comes before
+	/// `self`, the iterator will simply be empty.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::Weekday;
+	///
+	/// assert_eq!(
+	///     Weekday::Monday.range_bounded(Weekday::Wednesday).collect::<Vec<_>>(),
+	///     [Weekday::Monday, Weekday::Tuesday, Weekday::Wednesday],
+	/// );
+	///
+	/// // Backwards ranges are empty.
+	/// assert_eq!(Weekday::Friday.range_bounded(Weekday::Monday).len(), 0);
+	/// ```
+	pub const fn range_bounded(self, end: Self) -> RangedWeekdayIter {
+		let start = self as u8;
+		let end = end as u8;
+		RangedWeekdayIter {
+			start: self,
+			front: 0,
+			back:
+				if start <= end { end - start + 1 }
+				else { 0 },
+		}
+	}
+
 	#[must_use]
 	/// # As Str.
 	///
@@ -370,6 +443,45 @@ impl Weekday {
 	/// ```
 	pub fn now() -> Self { Utc2k::now().weekday() }
 
+	#[must_use]
+	/// # Try From U8.
+	///
+	/// Return the `Weekday` corresponding to the given number — `1` for
+	/// Sunday through `7` for Saturday — or `None` if it is out of range.
+	///
+	/// Unlike the `From<u8>` implementation — which wraps out-of-range values
+	/// around to keep arithmetic simple — this is strict, making it better
+	/// suited for validation.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::Weekday;
+	///
+	/// assert_eq!(Weekday::try_from_u8(1), Some(Weekday::Sunday));
+	/// assert_eq!(Weekday::try_from_u8(7), Some(Weekday::Saturday));
+	///
+	/// // Strict.
+	/// assert_eq!(Weekday::try_from_u8(0), None);
+	/// assert_eq!(Weekday::try_from_u8(8), None);
+	///
+	/// // Versus wrapping.
+	/// assert_eq!(Weekday::from(0_u8), Weekday::Saturday);
+	/// assert_eq!(Weekday::from(8_u8), Weekday::Sunday);
+	/// ```
+	pub const fn try_from_u8(src: u8) -> Option<Self> {
+		match src {
+			1 => Some(Self::Sunday),
+			2 => Some(Self::Monday),
+			3 => Some(Self::Tuesday),
+			4 => Some(Self::Wednesday),
+			5 => Some(Self::Thursday),
+			6 => Some(Self::Friday),
+			7 => Some(Self::Saturday),
+			_ => None,
+		}
+	}
+
 	#[inline]
 	#[must_use]
 	/// # Tomorrow.
@@ -445,6 +557,57 @@ impl Weekday {
 	/// );
 	/// ```
 	pub fn last_in_month(self, y: u16, m: u8) -> Option<u8> {
+		self.month_bounds(y, m).map(|(_, last)| last)
+	}
+
+	#[must_use]
+	/// # Count in Month.
+	///
+	/// Return the number of times this weekday occurs in a given year/month,
+	/// which will always be either `4` or `5`.
+	///
+	/// This will only return `None` if you pass a bad year and/or month.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::Weekday;
+	///
+	/// // There were five Mondays in October 2023, but only four Fridays.
+	/// assert_eq!(Weekday::Monday.count_in_month(2023, 10), Some(5));
+	/// assert_eq!(Weekday::Friday.count_in_month(2023, 10), Some(4));
+	/// ```
+	pub fn count_in_month(self, y: u16, m: u8) -> Option<u8> {
+		self.month_bounds(y, m).map(|(first, last)| (last - first) / 7 + 1)
+	}
+
+	/// # All in Month.
+	///
+	/// Return an iterator over the days corresponding to each occurrence of
+	/// this weekday in a given year/month.
+	///
+	/// The iterator will be empty if you pass a bad year and/or month.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use utc2k::Weekday;
+	///
+	/// assert_eq!(
+	///     Weekday::Monday.all_in_month(2023, 10).collect::<Vec<u8>>(),
+	///     [2, 9, 16, 23, 30],
+	/// );
+	/// ```
+	pub fn all_in_month(self, y: u16, m: u8) -> impl Iterator<Item = u8> {
+		let (first, last) = self.month_bounds(y, m).unwrap_or((1, 0));
+		(first..=last).step_by(7)
+	}
+
+	/// # First and Last Days.
+	///
+	/// Return the first and last day corresponding to this weekday in a given
+	/// year/month, or `None` if the year and/or month are bad.
+	fn month_bounds(self, y: u16, m: u8) -> Option<(u8, u8)> {
 		// Load the first date of the month, and make sure it is sane.
 		let first = Utc2k::new(y, m, 1, 0, 0, 0);
 		if (y, m, 1) != first.ymd() { return None; }
@@ -463,7 +626,7 @@ impl Weekday {
 		let n = (first.month_size() - d).wrapping_div(7);
 
 		// Add them and we have our answer!
-		Some(d + n * 7)
+		Some((d, d + n * 7))
 	}
 
 	#[must_use]
@@ -518,6 +681,22 @@ impl Weekday {
 }
 
 impl Weekday {
+	/// # From `u8` (Wrapping).
+	///
+	/// This is a const equivalent of the `From<u8>` implementation, wrapping
+	/// out-of-range values.
+	pub(crate) const fn from_u8(src: u8) -> Self {
+		match src % 7 {
+			1 => Self::Sunday,
+			2 => Self::Monday,
+			3 => Self::Tuesday,
+			4 => Self::Wednesday,
+			5 => Self::Thursday,
+			6 => Self::Friday,
+			_ => Self::Saturday,
+		}
+	}
+
 	/// # From Abbreviation Bytes.
 	///
 	/// This matches the first three non-whitespace bytes, case-insensitively,
@@ -593,6 +772,62 @@ impl Iterator for RepeatingWeekdayIter {
 
 
 
+#[derive(Debug, Clone)]
+/// # Bounded Weekdays.
+///
+/// This iterator yields each `Weekday` in a fixed range, once, as returned by
+/// [`Weekday::range`] or [`Weekday::range_bounded`].
+pub struct RangedWeekdayIter {
+	/// # First Weekday.
+	start: Weekday,
+
+	/// # Front Offset (From Start).
+	front: u8,
+
+	/// # Back Offset (From Start).
+	back: u8,
+}
+
+impl Iterator for RangedWeekdayIter {
+	type Item = Weekday;
+
+	/// # Next Weekday.
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.front < self.back {
+			let next = self.start + self.front;
+			self.front += 1;
+			Some(next)
+		}
+		else { None }
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl DoubleEndedIterator for RangedWeekdayIter {
+	/// # Previous Weekday.
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.front < self.back {
+			self.back -= 1;
+			Some(self.start + self.back)
+		}
+		else { None }
+	}
+}
+
+impl ExactSizeIterator for RangedWeekdayIter {
+	#[inline]
+	fn len(&self) -> usize { usize::from(self.back - self.front) }
+}
+
+impl FusedIterator for RangedWeekdayIter {}
+
+
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -643,6 +878,13 @@ mod tests {
 
 		assert_eq!(Weekday::from(0_u64), Weekday::Saturday);
 
+		// Strict.
+		for i in 0..=u8::MAX {
+			let weekday = Weekday::try_from_u8(i);
+			if (1..=7).contains(&i) { assert_eq!(weekday, Some(Weekday::from(i))); }
+			else { assert!(weekday.is_none(), "Weekday {i} should be invalid."); }
+		}
+
 		let many: Vec<Weekday> = (1..=35_u32)
 			.map(Weekday::from)
 			.collect();
@@ -717,7 +959,91 @@ mod tests {
 
 			// And make sure one more is too many.
 			assert_eq!(weekday.nth_in_month(2023, 10, dates.len() as u8 + 1), None);
+
+			// The counts and lists should match too.
+			assert_eq!(weekday.count_in_month(2023, 10), u8::try_from(dates.len()).ok());
+			assert_eq!(weekday.all_in_month(2023, 10).collect::<Vec<_>>(), dates);
 		}
+
+		// Bad dates should come up empty.
+		assert_eq!(Weekday::Monday.count_in_month(2023, 13), None);
+		assert_eq!(Weekday::Monday.all_in_month(2023, 13).next(), None);
+		assert_eq!(Weekday::Monday.count_in_month(2100, 1), None);
+
+		// Every month, every weekday.
+		for y in 2000..=2099 {
+			for m in crate::Month::all() {
+				let first = m.weekday_of_first(y).expect("Missing weekday.");
+				for weekday in Weekday::all() {
+					let all = weekday.all_in_month(y, m as u8).collect::<Vec<_>>();
+					assert_eq!(
+						all,
+						(1..=5).filter_map(|n| weekday.nth_in_month(y, m as u8, n)).collect::<Vec<_>>(),
+					);
+					assert_eq!(weekday.count_in_month(y, m as u8), u8::try_from(all.len()).ok());
+					if weekday == first { assert_eq!(all[0], 1); }
+				}
+			}
+		}
+	}
+
+	#[test]
+	/// # Bounded Ranges.
+	fn t_range() {
+		for start in Weekday::all() {
+			for (len, end) in (1..=7).zip(start) {
+				// Forward.
+				let range = start.range(end);
+				assert_eq!(range.len(), len);
+				let forward: Vec<Weekday> = range.collect();
+				assert_eq!(forward.len(), len);
+				assert_eq!(forward, start.into_iter().take(len).collect::<Vec<_>>());
+
+				// Backward.
+				let mut backward: Vec<Weekday> = start.range(end).rev().collect();
+				backward.reverse();
+				assert_eq!(forward, backward);
+
+				// Mixed.
+				let mut range = start.range(end);
+				assert_eq!(range.next(), Some(start));
+				if len == 1 { assert!(range.next_back().is_none()); }
+				else {
+					assert_eq!(range.next_back(), Some(end));
+					assert_eq!(range.len(), len - 2);
+				}
+			}
+		}
+
+		// Non-wrapping ranges should match the wrapping ones when in order,
+		// and be empty otherwise.
+		for start in Weekday::all() {
+			for end in Weekday::all() {
+				if start <= end {
+					assert_eq!(
+						start.range_bounded(end).collect::<Vec<_>>(),
+						start.range(end).collect::<Vec<_>>(),
+					);
+				}
+				else {
+					let mut range = start.range_bounded(end);
+					assert_eq!(range.len(), 0);
+					assert!(range.next().is_none());
+					assert!(range.next_back().is_none());
+				}
+			}
+		}
+
+		// Explicitly check a wrapping range.
+		let mut range = Weekday::all()[7 - 2].range(Weekday::all()[1]);
+		assert_eq!(range.len(), 4);
+		assert_eq!(range.next(), Some(Weekday::all()[7 - 2]));
+		assert_eq!(range.next_back(), Some(Weekday::all()[1]));
+		assert_eq!(range.next(), Some(Weekday::all()[7 - 1]));
+		assert_eq!(range.next_back(), Some(Weekday::all()[0]));
+		assert_eq!(range.len(), 0);
+		assert!(range.next().is_none());
+		assert!(range.next_back().is_none());
 	}
 
 	#[test]